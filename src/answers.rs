@@ -0,0 +1,250 @@
+use crate::runner::{run_day, RunError};
+use crate::solution::Answer;
+use crate::util::{AdventHelper, InputSource};
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::{fs, panic};
+
+/// Expected answers, read from lines of the form `day.part = value`.
+/// Empty lines and lines starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct Answers {
+    expected: FxHashMap<(u32, u32), String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Answers {
+    /// Loads the answers in `file_path`, a missing file is treated as having no answers at all.
+    pub fn load(file_path: &str) -> Result<Answers, String> {
+        if !Path::new(file_path).exists() {
+            return Ok(Answers::default());
+        }
+        let contents = fs::read_to_string(file_path)
+            .map_err(|e| format!("Could not read {}: {}", file_path, e))?;
+        Answers::parse(&contents).map_err(|e| format!("{}: {}", file_path, e))
+    }
+
+    pub fn parse(contents: &str) -> Result<Answers, String> {
+        let mut expected = FxHashMap::default();
+        for (line_number, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = parse_line(line)
+                .ok_or_else(|| format!("Malformed answer on line {}: {}", line_number + 1, line))?;
+            expected.insert(key, value);
+        }
+        Ok(Answers { expected })
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.expected.get(&(day, part)).map(|s| s.as_str())
+    }
+
+    pub fn insert(&mut self, day: u32, part: u32, answer: &Answer) {
+        self.expected.insert((day, part), answer.to_string());
+    }
+
+    pub fn verify(&self, day: u32, part: u32, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Missing,
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for ((day, part), answer) in self.expected.iter().sorted() {
+            writeln!(f, "{}.{} = {}", day, part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+/// The verdict on one part, or why a day could not be checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    NoInput {
+        day: u32,
+        input_file: String,
+    },
+    Panicked {
+        day: u32,
+    },
+    Error(RunError),
+    Part {
+        day: u32,
+        part: u32,
+        answer: Answer,
+        verdict: Verdict,
+    },
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Check::NoInput { day, input_file } => {
+                write!(f, "Day {}: no input file {}", day, input_file)
+            }
+            Check::Panicked { day } => write!(f, "Day {}: FAIL (panicked)", day),
+            Check::Error(e) => write!(f, "{}", e),
+            Check::Part {
+                day,
+                part,
+                answer,
+                verdict,
+            } => match verdict {
+                Verdict::Pass => write!(f, "Day {}, Part {}: pass ({})", day, part, answer),
+                Verdict::Fail { expected } => write!(
+                    f,
+                    "Day {}, Part {}: FAIL (expected {}, got {})",
+                    day, part, expected, answer
+                ),
+                Verdict::Missing => {
+                    write!(f, "Day {}, Part {}: missing (got {})", day, part, answer)
+                }
+            },
+        }
+    }
+}
+
+/// Solves `day` and compares its answers with the answers file of the input, see
+/// [`AdventHelper::answers_file`]. Days that are not implemented yet have nothing to check.
+/// Fails if the answers file cannot be loaded.
+pub fn check_day(
+    year: u32,
+    day: u32,
+    source: &InputSource,
+    parts: &[u32],
+) -> Result<Vec<Check>, String> {
+    let advent = AdventHelper::with_source(year, day, source.clone());
+    if !advent.has_input() {
+        let input_file = advent.input_file();
+        return Ok(vec![Check::NoInput { day, input_file }]);
+    }
+    let expected = match advent.answers_file() {
+        Some(file) => Answers::load(&file)?,
+        None => Answers::default(),
+    };
+    let Ok(day_run) = panic::catch_unwind(|| run_day(year, day, source, parts)) else {
+        return Ok(vec![Check::Panicked { day }]);
+    };
+    let checks = match day_run {
+        Ok(day_run) => day_run
+            .answers
+            .into_iter()
+            .map(|(part, answer)| Check::Part {
+                day,
+                part,
+                verdict: expected.verify(day, part, &answer),
+                answer,
+            })
+            .collect(),
+        Err(RunError::NotImplemented { .. }) => vec![],
+        Err(e) => vec![Check::Error(e)],
+    };
+    Ok(checks)
+}
+
+/// Counts of the verdicts of a number of [`Check`]s, where errors count as failures.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub missing: usize,
+}
+
+impl Summary {
+    pub fn add(&mut self, check: &Check) {
+        match check {
+            Check::NoInput { .. } => {}
+            Check::Panicked { .. } | Check::Error(_) => self.failed += 1,
+            Check::Part { verdict, .. } => match verdict {
+                Verdict::Pass => self.passed += 1,
+                Verdict::Fail { .. } => self.failed += 1,
+                Verdict::Missing => self.missing += 1,
+            },
+        }
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} missing",
+            self.passed, self.failed, self.missing
+        )
+    }
+}
+
+fn parse_line(line: &str) -> Option<((u32, u32), String)> {
+    let (key, value) = line.split_once('=')?;
+    let (day, part) = key.trim().split_once('.')?;
+    let key = (day.parse().ok()?, part.parse().ok()?);
+    Some((key, value.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{check_day, Answers, Check, Summary, Verdict};
+    use crate::solution::Answer;
+    use crate::util::InputSource;
+
+    #[test]
+    fn verifies_answers() {
+        let answers = Answers::parse("# comment\n\n1.1 = 42\n1.2=abc\n").unwrap();
+
+        assert_eq!(answers.verify(1, 1, &Answer::Number(42)), Verdict::Pass);
+        assert_eq!(
+            answers.verify(1, 2, &Answer::Number(42)),
+            Verdict::Fail {
+                expected: "abc".to_string()
+            }
+        );
+        assert_eq!(answers.verify(2, 1, &Answer::Number(42)), Verdict::Missing);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert_eq!(
+            Answers::parse("1.1 = 42\n1 = 42\n").unwrap_err(),
+            "Malformed answer on line 2: 1 = 42"
+        );
+    }
+
+    #[test]
+    fn checks_days() {
+        let source = InputSource::File("no/such/file.txt".to_string());
+        let checks = check_day(2023, 1, &source, &[1, 2]).unwrap();
+        assert_eq!(
+            checks,
+            vec![Check::NoInput {
+                day: 1,
+                input_file: "no/such/file.txt".to_string()
+            }]
+        );
+
+        let mut summary = Summary::default();
+        for check in checks
+            .iter()
+            .chain(&check_day(2023, 1, &InputSource::Puzzle, &[1, 2]).unwrap())
+        {
+            summary.add(check);
+        }
+        assert_eq!(summary.to_string(), "2 passed, 0 failed, 0 missing");
+    }
+}
@@ -0,0 +1,251 @@
+use crate::runner::{format_duration, run_day, RunError, Timings};
+use crate::util::InputSource;
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+pub const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let sorted = samples.iter().copied().sorted().collect_vec();
+        let n = sorted.len();
+        let median = if n % 2 == 0 {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        let nanos = sorted.iter().map(|d| d.as_nanos() as f64).collect_vec();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:>8}, median {:>8}, mean {:>8}, stddev {:>8}",
+            format_duration(self.min),
+            format_duration(self.median),
+            format_duration(self.mean),
+            format_duration(self.stddev)
+        )
+    }
+}
+
+/// Benchmark results of a single day, per phase (see [`PHASES`]).
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub day: u32,
+    pub runs: usize,
+    pub phases: Vec<(&'static str, Stats)>,
+}
+
+impl DayBench {
+    /// One line per phase, with the change compared to `previous` when it has that phase
+    pub fn report(&self, previous: Option<&Baseline>) -> String {
+        let mut lines = vec![format!("Day {} ({} runs)", self.day, self.runs)];
+        for (phase, stats) in &self.phases {
+            let delta = previous
+                .and_then(|p| p.delta(self.day, phase, stats))
+                .map(|d| format!(" ({:+.1}%)", d))
+                .unwrap_or_default();
+            lines.push(format!("  {:<5} {}{}", phase, stats, delta));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Solves `day` once to warm up and then `runs` more times, collecting statistics per phase.
+pub fn bench_day(
+    year: u32,
+    day: u32,
+    source: &InputSource,
+    parts: &[u32],
+    runs: usize,
+) -> Result<DayBench, RunError> {
+    run_day(year, day, source, parts)?;
+    let samples: Vec<Timings> = (0..runs)
+        .map(|_| run_day(year, day, source, parts).map(|day_run| day_run.timings))
+        .collect::<Result<_, _>>()?;
+    let phases = PHASES
+        .iter()
+        .filter(|phase| match **phase {
+            "part1" => parts.contains(&1),
+            "part2" => parts.contains(&2),
+            _ => true,
+        })
+        .map(|phase| {
+            let durations = samples
+                .iter()
+                .map(|timings| match *phase {
+                    "parse" => timings.parse,
+                    "part1" => timings.part1,
+                    _ => timings.part2,
+                })
+                .collect_vec();
+            (*phase, Stats::from_samples(&durations))
+        })
+        .collect();
+    Ok(DayBench { day, runs, phases })
+}
+
+/// Median durations of a previous benchmark of one year, stored as `day.phase = nanoseconds` lines.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    medians: FxHashMap<(u32, String), Duration>,
+}
+
+impl Baseline {
+    /// Loads the baseline in `file_path`, a missing file is treated as an empty baseline.
+    pub fn load(file_path: &str) -> Result<Baseline, String> {
+        if !Path::new(file_path).exists() {
+            return Ok(Baseline::default());
+        }
+        let contents = fs::read_to_string(file_path)
+            .map_err(|e| format!("Could not read {}: {}", file_path, e))?;
+        Baseline::parse(&contents).map_err(|e| format!("{}: {}", file_path, e))
+    }
+
+    pub fn parse(contents: &str) -> Result<Baseline, String> {
+        let mut medians = FxHashMap::default();
+        for (line_number, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, nanos) = parse_line(line).ok_or_else(|| {
+                format!("Malformed baseline on line {}: {}", line_number + 1, line)
+            })?;
+            medians.insert(key, Duration::from_nanos(nanos));
+        }
+        Ok(Baseline { medians })
+    }
+
+    pub fn save(&self, file_path: &str) -> Result<(), String> {
+        fs::write(file_path, self.to_string())
+            .map_err(|e| format!("Could not write {}: {}", file_path, e))
+    }
+
+    pub fn record(&mut self, bench: &DayBench) {
+        for (phase, stats) in &bench.phases {
+            self.medians
+                .insert((bench.day, phase.to_string()), stats.median);
+        }
+    }
+
+    pub fn get(&self, day: u32, phase: &str) -> Option<Duration> {
+        self.medians.get(&(day, phase.to_string())).copied()
+    }
+
+    /// Relative change of the median of `stats` compared to the baseline, in percent
+    pub fn delta(&self, day: u32, phase: &str, stats: &Stats) -> Option<f64> {
+        let previous = self.get(day, phase)?.as_nanos() as f64;
+        if previous == 0.0 {
+            return None;
+        }
+        Some((stats.median.as_nanos() as f64 - previous) / previous * 100.0)
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for ((day, phase), median) in self.medians.iter().sorted() {
+            writeln!(f, "{}.{} = {}", day, phase, median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_line(line: &str) -> Option<((u32, String), u64)> {
+    let (key, value) = line.split_once('=')?;
+    let (day, phase) = key.trim().split_once('.')?;
+    Some((
+        (day.parse().ok()?, phase.to_string()),
+        value.trim().parse().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{bench_day, Baseline, DayBench, Stats};
+    use crate::runner::RunError;
+    use crate::util::InputSource;
+    use std::fs;
+    use std::time::Duration;
+
+    #[test]
+    fn computes_statistics() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev, Duration::from_nanos(1_118_034));
+    }
+
+    #[test]
+    fn compares_against_baseline() {
+        let stats = Stats::from_samples(&[Duration::from_millis(11)]);
+        let mut baseline = Baseline::default();
+        baseline.record(&DayBench {
+            day: 3,
+            runs: 1,
+            phases: vec![("part1", Stats::from_samples(&[Duration::from_millis(10)]))],
+        });
+        let bench = DayBench {
+            day: 3,
+            runs: 1,
+            phases: vec![("part1", stats)],
+        };
+
+        let reloaded = Baseline::parse(&baseline.to_string()).unwrap();
+
+        assert_eq!(reloaded, baseline);
+        assert_eq!(
+            reloaded.delta(3, "part1", &stats).map(|d| d.round()),
+            Some(10.0)
+        );
+        assert_eq!(reloaded.delta(3, "part2", &stats), None);
+        assert!(bench.report(Some(&reloaded)).ends_with("(+10.0%)"));
+        assert!(bench
+            .report(None)
+            .starts_with("Day 3 (1 runs)\n  part1 min"));
+    }
+
+    #[test]
+    fn reports_days_that_cannot_run() {
+        assert_eq!(
+            bench_day(2022, 7, &InputSource::Puzzle, &[1, 2], 3).unwrap_err(),
+            RunError::NotImplemented { day: 7 }
+        );
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        fs::write(file.path(), "not a hand\n").unwrap();
+        let source = InputSource::File(file.path().to_str().unwrap().to_string());
+        assert!(matches!(
+            bench_day(2023, 7, &source, &[1, 2], 3),
+            Err(RunError::Parse { day: 7, .. })
+        ));
+    }
+}
@@ -0,0 +1,169 @@
+use crate::submit::Outcome;
+use crate::util::AdventHelper;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Talks to the Advent of Code website, or to any server mimicking it at `base_url`.
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    /// Reads the session cookie from `AOC_SESSION` and the optional base URL from `AOC_BASE_URL`,
+    /// both of which may be set in `.env`.
+    pub fn from_env() -> Result<Self, String> {
+        dotenv::dotenv().ok();
+        let session = env::var("AOC_SESSION")
+            .map_err(|_| "AOC_SESSION is not set, add it to .env".to_string())?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or(DEFAULT_BASE_URL.to_string());
+        Ok(Self::new(&base_url, &session))
+    }
+
+    pub fn input_url(&self, year: u32, day: u32) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    pub fn download_input(&self, year: u32, day: u32) -> Result<String, String> {
+        let url = self.input_url(year, day);
+        ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|e| format!("Could not download {}: {}", url, e))?
+            .into_string()
+            .map_err(|e| format!("Could not read {}: {}", url, e))
+    }
+
+    /// Posts `answer` for `part` of `day` and interprets the response.
+    pub fn submit_answer(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+    ) -> Result<Outcome, String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| format!("Could not submit to {}: {}", url, e))?
+            .into_string()
+            .map_err(|e| format!("Could not read {}: {}", url, e))?;
+        Outcome::from_response(&response)
+    }
+
+    /// Downloads the input of `day` to `file_path` unless it is already there.
+    /// Returns whether the input was downloaded.
+    pub fn fetch_input(&self, year: u32, day: u32, file_path: &str) -> Result<bool, String> {
+        if Path::new(file_path).exists() {
+            return Ok(false);
+        }
+        let input = self.download_input(year, day)?;
+        if let Some(dir) = Path::new(file_path).parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("Could not create {:?}: {}", dir, e))?;
+        }
+        fs::write(file_path, input).map_err(|e| format!("Could not write {}: {}", file_path, e))?;
+        Ok(true)
+    }
+
+    /// Downloads the missing inputs of `days` to their puzzle input files, reporting each day to
+    /// `progress`. Stops at the first day that fails.
+    pub fn fetch_inputs(
+        &self,
+        year: u32,
+        days: &[u32],
+        mut progress: impl FnMut(String),
+    ) -> Result<(), String> {
+        for &day in days {
+            let file = AdventHelper::new(year, day).input_file();
+            match self.fetch_input(year, day, &file) {
+                Ok(true) => progress(format!("Day {}: downloaded {}", day, file)),
+                Ok(false) => progress(format!("Day {}: {} already exists", day, file)),
+                Err(e) => return Err(format!("Day {}: {}", day, e)),
+            }
+        }
+        Ok(())
+    }
+}
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+#[cfg(test)]
+mod tests {
+    use crate::client::Client;
+    use crate::submit::Outcome;
+    use std::fs;
+    use std::thread;
+    use tiny_http::{Response, Server};
+
+    #[test]
+    fn fetches_input_once() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+        let handle = thread::spawn(move || {
+            let request = server.recv().unwrap();
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|h| h.field.equiv("Cookie"))
+                .map(|h| h.value.to_string());
+            let url = request.url().to_string();
+            request.respond(Response::from_string("1\n2\n")).unwrap();
+            (url, cookie)
+        });
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("resources/day3.txt");
+        let file = file.to_str().unwrap();
+        let client = Client::new(&base_url, "secret");
+
+        assert_eq!(client.fetch_input(2023, 3, file), Ok(true));
+        assert_eq!(client.fetch_input(2023, 3, file), Ok(false));
+
+        assert_eq!(fs::read_to_string(file).unwrap(), "1\n2\n");
+        assert_eq!(
+            handle.join().unwrap(),
+            (
+                "/2023/day/3/input".to_string(),
+                Some("session=secret".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn submits_answers() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+        let handle = thread::spawn(move || {
+            let mut request = server.recv().unwrap();
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+            let url = request.url().to_string();
+            let response = "<p>That's not the right answer; your answer is too low.</p>";
+            request.respond(Response::from_string(response)).unwrap();
+            (url, body)
+        });
+        let client = Client::new(&base_url, "secret");
+
+        assert_eq!(client.submit_answer(2023, 3, 2, "42"), Ok(Outcome::TooLow));
+        assert_eq!(
+            handle.join().unwrap(),
+            (
+                "/2023/day/3/answer".to_string(),
+                "level=2&answer=42".to_string()
+            )
+        );
+    }
+}
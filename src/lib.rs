@@ -0,0 +1,18 @@
+//! Advent of Code solutions, one module per year, together with the `util` toolbox they are
+//! built on.
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+pub mod util;
+pub mod watch;
+pub mod year2023;
+
+extern crate core;
+extern crate dotenv;
+
+pub use solution::{solution, years, Answer, Solution, Solver, YEARS};
@@ -1,113 +1,343 @@
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day2;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod util;
-
-extern crate core;
-extern crate dotenv;
-
-use std::env;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
+use std::{env, io, process};
 
-use clap::{arg, Parser};
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+
+use advent_of_code_2023::answers::{check_day, Summary};
+use advent_of_code_2023::bench::{bench_day, Baseline};
+use advent_of_code_2023::client::Client;
+use advent_of_code_2023::runner::{
+    export_day, parse_days, run_day, run_days_parallel, visualize_day, DayRun, RunError, Timings,
+};
+use advent_of_code_2023::scaffold::new_day;
+use advent_of_code_2023::submit::{submit, History, HISTORY_FILE};
+use advent_of_code_2023::util::InputSource;
+use advent_of_code_2023::watch::watch;
+use advent_of_code_2023::years;
 
 /// Simple program to greet a person
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
+#[command(subcommand_negates_reqs = true)]
 struct Args {
-    /// Day to run
-    #[arg(value_parser = clap::value_parser!(i32).range(0..=25))]
-    day: i32,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Days to run, e.g. `5`, `5,7,10-12` or `0` for all days
+    #[arg(required = true, value_parser = |s: &str| parse_days(s).map(Days))]
+    days: Option<Days>,
+
+    /// Year of the puzzles
+    #[arg(long, env = "AOC_YEAR", global = true, default_value_t = 2023)]
+    year: u32,
 
-    /// Use test file instead (resources/day<day>.test.txt)
+    /// Only run this part
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+
+    /// Use test file instead (resources/<year>/day<day>.test.txt)
     #[arg(long, env, default_value_t = false)]
     test: bool,
 
+    /// Use the n-th example instead (resources/<year>/day<day>.test<n>.txt, n = 1 is the same as --test)
+    #[arg(long, value_name = "N", conflicts_with = "test")]
+    example: Option<u32>,
+
+    /// Read the input from this file instead, `-` reads it from stdin
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["test", "example"])]
+    input: Option<String>,
+
     /// Measure execution time
     #[arg(short, long, env, default_value_t = false)]
     time: bool,
+
+    /// Compare answers with resources/<year>/answers.txt (or answers.test.txt with --test)
+    #[arg(long, default_value_t = false)]
+    check: bool,
+
+    /// Benchmark the selected days by solving them N times after a warm-up run
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+
+    /// Save the median times of the benchmark to this file
+    #[arg(long, value_name = "FILE", requires = "bench")]
+    save_baseline: Option<String>,
+
+    /// Compare the benchmark with median times saved earlier using --save-baseline
+    #[arg(long, value_name = "FILE", requires = "bench")]
+    baseline: Option<String>,
+
+    /// Solve the selected days concurrently, the output is still printed in order
+    #[arg(long, default_value_t = false)]
+    parallel: bool,
+
+    /// Solve the selected days again whenever their input files, or their source files when
+    /// running a build of this checkout, change
+    #[arg(long, default_value_t = false, conflicts_with_all = ["bench", "check", "parallel"])]
+    watch: bool,
+
+    /// Draw the puzzle state after the answers of days that support it, in colour when printing
+    /// to a terminal and NO_COLOR is not set
+    #[arg(long, default_value_t = false, conflicts_with_all = ["bench", "check", "watch"])]
+    visualize: bool,
+
+    /// Save pictures of the puzzle state of a day that supports it, a single picture to a .png or
+    /// .svg file and an animation to a .gif file or to a directory of numbered PNGs. A single
+    /// picture saved to a .gif file is an animation of one frame
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["bench", "check", "watch"])]
+    export: Option<PathBuf>,
+
+    /// Output format of the answers, with json and csv errors go to stderr
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Download missing puzzle inputs to resources/<year>/day<day>.txt, using AOC_SESSION from .env
+    Fetch {
+        /// Days to download, e.g. `5`, `5,7,10-12` or `0` for all days
+        #[arg(value_parser = |s: &str| parse_days(s).map(Days))]
+        days: Days,
+    },
+    /// Submit an answer, refusing answers that earlier attempts in resources/submissions.txt rule out
+    Submit {
+        day: u32,
+
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+
+        /// The answer to submit, computed from the puzzle input when left out
+        answer: Option<String>,
+    },
+    /// Create the module, input files and answer stubs of a new day in this checkout
+    New {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
 }
+
+#[derive(Clone, Debug)]
+struct Days(Vec<u32>);
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
 fn main() {
     let args = Args::parse();
 
-    if args.test {
-        env::set_var("TEST", "true");
+    let year = args.year;
+    match &args.command {
+        Some(Command::Fetch { days }) => return fetch(year, &days.0),
+        Some(Command::Submit { day, part, answer }) => {
+            return submit_answer(year, *day, *part, answer.clone())
+        }
+        Some(Command::New { day }) => return new(year, *day),
+        None => {}
     }
 
-    if args.day == 0 {
-        let start = Instant::now();
-        for d in 1..=25 {
-            run(d, args.time);
-            println!();
+    if !years().any(|y| y == year) {
+        let years = years()
+            .map(|y| y.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Args::command()
+            .error(
+                ErrorKind::InvalidValue,
+                format!("There are no solutions for {}, only for {}", year, years),
+            )
+            .exit()
+    }
+    let days = &args.days.as_ref().unwrap().0;
+    if args.input.is_some() && days.len() > 1 {
+        Args::command()
+            .error(ErrorKind::ArgumentConflict, "--input requires a single day")
+            .exit()
+    }
+    if args.export.is_some() && days.len() > 1 {
+        Args::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--export requires a single day",
+            )
+            .exit()
+    }
+    let source = input_source(&args);
+    let parts = args.part.map(|p| vec![p]).unwrap_or(vec![1, 2]);
+
+    if args.watch {
+        if source == InputSource::Stdin {
+            Args::command()
+                .error(ErrorKind::ArgumentConflict, "stdin cannot be watched")
+                .exit()
+        }
+        watch(year, days, &source, &parts);
+    }
+
+    if let Some(runs) = args.bench {
+        bench(year, days, &source, &parts, runs as usize, &args);
+        return;
+    }
+
+    if args.check {
+        if !check(year, days, &source, &parts) {
+            process::exit(1)
+        }
+        return;
+    }
+
+    if args.format == Format::Csv {
+        println!("{}", DayRun::CSV_HEADER);
+    }
+
+    let start = Instant::now();
+    let mut timings = Timings::default();
+    let mut print = |day_run: Result<DayRun, RunError>| {
+        let day = day_run.as_ref().ok().map(|day_run| day_run.day);
+        timings += print_day_run(day_run, args.time, args.format);
+        if let Some(day) = day.filter(|_| args.visualize && args.format == Format::Text) {
+            visualize(year, day, &source);
         }
-        if args.time {
-            println!("Total Time: {} ms", start.elapsed().as_millis());
+        if let (Some(day), Some(path)) = (day, &args.export) {
+            export(year, day, &source, path);
         }
+        if days.len() > 1 && args.format == Format::Text {
+            println!();
+        }
+    };
+    if args.parallel {
+        run_days_parallel(year, days, &source, &parts)
+            .into_iter()
+            .for_each(print);
     } else {
-        run(args.day, args.time)
+        for &day in days {
+            print(run_day(year, day, &source, &parts));
+        }
+    }
+    let show_totals = args.time || args.parallel;
+    if days.len() > 1 && show_totals && args.format == Format::Text {
+        println!("Total Time: {} ms", start.elapsed().as_millis());
+        println!("Total CPU Time: {}", timings);
     }
 }
 
-fn run(day: i32, time: bool) {
-    let start = Instant::now();
-    match day {
-        1 => day1::main(),
-        2 => day2::main(),
-        3 => day3::main(),
-        4 => day4::main(),
-        5 => day5::main(),
-        6 => day6::main(),
-        7 => day7::main(),
-        8 => day8::main(),
-        9 => day9::main(),
-        10 => day10::main(),
-        11 => day11::main(),
-        12 => day12::main(),
-        13 => day13::main(),
-        14 => day14::main(),
-        15 => day15::main(),
-        16 => day16::main(),
-        17 => day17::main(),
-        18 => day18::main(),
-        19 => day19::main(),
-        20 => day20::main(),
-        21 => day21::main(),
-        22 => day22::main(),
-        23 => day23::main(),
-        24 => day24::main(),
-        25 => day25::main(),
-        other => {
-            println!("Day {} not yet implemented 😅", other)
+fn input_source(args: &Args) -> InputSource {
+    match (&args.input, args.example) {
+        (Some(path), _) if path == "-" => InputSource::Stdin,
+        (Some(path), _) => InputSource::File(path.clone()),
+        (None, Some(n)) => InputSource::Example(n),
+        (None, None) if args.test => InputSource::Example(1),
+        (None, None) => InputSource::Puzzle,
+    }
+}
+
+fn print_day_run(day_run: Result<DayRun, RunError>, time: bool, format: Format) -> Timings {
+    match day_run {
+        Ok(day_run) => {
+            match format {
+                Format::Text => println!("{}", day_run.to_text(time)),
+                Format::Json => println!("{}", day_run.to_json()),
+                Format::Csv => println!("{}", day_run.to_csv()),
+            }
+            day_run.timings
+        }
+        // Keep the records on stdout parseable
+        Err(e) if format != Format::Text => {
+            eprintln!("{}", e);
+            Timings::default()
+        }
+        Err(e) => {
+            println!("{}", e);
+            Timings::default()
+        }
+    }
+}
+
+fn visualize(year: u32, day: u32, source: &InputSource) {
+    let ansi = io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none();
+    match visualize_day(year, day, source, ansi) {
+        Ok(Some(picture)) => print!("{}", picture),
+        Ok(None) => println!("Day {} has no visualization", day),
+        Err(e) => println!("{}", e),
+    }
+}
+
+fn export(year: u32, day: u32, source: &InputSource, path: &Path) {
+    match export_day(year, day, source, path) {
+        Ok(0) => eprintln!("Day {} has nothing to export", day),
+        Ok(n) => eprintln!("Saved {} frame(s) of day {} to {}", n, day, path.display()),
+        Err(e) => fail(format!("Could not export day {}: {}", day, e)),
+    }
+}
+
+fn check(year: u32, days: &[u32], source: &InputSource, parts: &[u32]) -> bool {
+    let mut summary = Summary::default();
+    for &day in days {
+        for check in check_day(year, day, source, parts).unwrap_or_else(|e| fail(e)) {
+            println!("{}", check);
+            summary.add(&check);
         }
     }
-    let duration = start.elapsed();
-    if time {
-        if duration.as_millis() < 3 {
-            println!("Time: {} μs", duration.as_micros())
-        } else {
-            println!("Time: {} ms", duration.as_millis())
+    println!();
+    println!("{}", summary);
+    summary.failed == 0
+}
+
+fn bench(year: u32, days: &[u32], source: &InputSource, parts: &[u32], runs: usize, args: &Args) {
+    let previous = args
+        .baseline
+        .as_deref()
+        .map(|file| Baseline::load(file).unwrap_or_else(|e| fail(e)));
+    let mut baseline = Baseline::default();
+    for &day in days {
+        match bench_day(year, day, source, parts, runs) {
+            Ok(day_bench) => {
+                println!("{}", day_bench.report(previous.as_ref()));
+                baseline.record(&day_bench);
+            }
+            Err(e) => println!("{}", e),
         }
     }
+    if let Some(file) = &args.save_baseline {
+        baseline.save(file).unwrap_or_else(|e| fail(e));
+        println!("Saved baseline to {}", file);
+    }
+}
+
+fn fetch(year: u32, days: &[u32]) {
+    let client = Client::from_env().unwrap_or_else(|e| fail(e));
+    client
+        .fetch_inputs(year, days, |progress| println!("{}", progress))
+        .unwrap_or_else(|e| fail(e));
+}
+
+fn submit_answer(year: u32, day: u32, part: u32, answer: Option<String>) {
+    let client = Client::from_env().unwrap_or_else(|e| fail(e));
+    let mut history = History::load(HISTORY_FILE).unwrap_or_else(|e| fail(e));
+    let attempt =
+        submit(&client, &mut history, year, day, part, answer).unwrap_or_else(|e| fail(e));
+    println!(
+        "Day {}, Part {}: {} is {}",
+        day, part, attempt.answer, attempt.outcome
+    );
+    history.save(HISTORY_FILE).unwrap_or_else(|e| fail(e));
+}
+
+fn new(year: u32, day: u32) {
+    match new_day(Path::new(env!("CARGO_MANIFEST_DIR")), year, day) {
+        Ok(files) => files.iter().for_each(|file| println!("Wrote {}", file)),
+        Err(e) => fail(e),
+    }
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1)
 }
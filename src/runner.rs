@@ -0,0 +1,406 @@
+use crate::solution::{solution, Answer, Solver};
+use crate::util::plot::{save, save_animation, Frame};
+use crate::util::{AdventHelper, InputSource, ParseError};
+use rayon::prelude::*;
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::ops::AddAssign;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Time spent in each phase of solving a day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn part(&self, part: u32) -> Duration {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            other => panic!("There is no part {}", other),
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+impl AddAssign for Timings {
+    fn add_assign(&mut self, rhs: Self) {
+        self.parse += rhs.parse;
+        self.part1 += rhs.part1;
+        self.part2 += rhs.part2;
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "parse {}, part 1 {}, part 2 {}, total {}",
+            format_duration(self.parse),
+            format_duration(self.part1),
+            format_duration(self.part2),
+            format_duration(self.total())
+        )
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    if duration.as_millis() < 3 {
+        format!("{} μs", duration.as_micros())
+    } else {
+        format!("{} ms", duration.as_millis())
+    }
+}
+
+/// The answers of one day together with the time it took to compute them.
+#[derive(Debug, Clone)]
+pub struct DayRun {
+    pub year: u32,
+    pub day: u32,
+    pub input_file: String,
+    pub answers: Vec<(u32, Answer)>,
+    pub timings: Timings,
+}
+
+impl DayRun {
+    pub const CSV_HEADER: &'static str = "year,day,part,answer,input_file,parse_us,elapsed_us";
+
+    /// The answers filled into the templates of the day, one line per part, followed by the
+    /// timings if `time` is set
+    pub fn to_text(&self, time: bool) -> String {
+        let solver = solution(self.year, self.day);
+        let mut lines = self
+            .answers
+            .iter()
+            .map(|(part, answer)| {
+                let template = solver.map_or("{}", |solver| solver.template(*part));
+                let text = template.replace("{}", &answer.to_string());
+                format!("Day {}, Part {}: {}", self.day, part, text)
+            })
+            .collect::<Vec<_>>();
+        if time {
+            lines.push(format!("Time: {}", self.timings));
+        }
+        lines.join("\n")
+    }
+
+    /// One JSON object per part, separated by newlines
+    pub fn to_json(&self) -> String {
+        self.answers
+            .iter()
+            .map(|(part, answer)| {
+                let answer = match answer {
+                    Answer::Number(n) => n.to_string(),
+                    Answer::Text(s) => json_string(s),
+                    Answer::Missing => "null".to_string(),
+                };
+                format!(
+                    r#"{{"year":{},"day":{},"part":{},"answer":{},"input_file":{},"parse_us":{},"elapsed_us":{}}}"#,
+                    self.year,
+                    self.day,
+                    part,
+                    answer,
+                    json_string(&self.input_file),
+                    self.timings.parse.as_micros(),
+                    self.timings.part(*part).as_micros()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// One CSV row per part, see [`DayRun::CSV_HEADER`]
+    pub fn to_csv(&self) -> String {
+        self.answers
+            .iter()
+            .map(|(part, answer)| {
+                format!(
+                    "{},{},{},{},{},{},{}",
+                    self.year,
+                    self.day,
+                    part,
+                    csv_field(&answer.to_string()),
+                    csv_field(&self.input_file),
+                    self.timings.parse.as_micros(),
+                    self.timings.part(*part).as_micros()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Why a day could not be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunError {
+    NotImplemented {
+        day: u32,
+    },
+    Input {
+        day: u32,
+        input_file: String,
+        error: String,
+    },
+    Parse {
+        day: u32,
+        input_file: String,
+        error: ParseError,
+    },
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunError::NotImplemented { day } => write!(f, "Day {} not yet implemented 😅", day),
+            RunError::Input {
+                day,
+                input_file,
+                error,
+            } => write!(f, "Day {}: could not read {}, {}", day, input_file, error),
+            RunError::Parse {
+                day,
+                input_file,
+                error,
+            } => write!(f, "Day {}: could not parse {}, {}", day, input_file, error),
+        }
+    }
+}
+
+/// Parses a selection of days such as `5,7,10-12`, where `0` selects all days.
+pub fn parse_days(s: &str) -> Result<Vec<u32>, String> {
+    let mut days = vec![];
+    for item in s.split(',') {
+        let (from, to) = item.split_once('-').unwrap_or((item, item));
+        let (Ok(from), Ok(to)) = (from.trim().parse::<u32>(), to.trim().parse::<u32>()) else {
+            return Err(format!("`{}` is neither a day nor a range of days", item));
+        };
+        if from == 0 && to == 0 {
+            days.extend(1..=25);
+        } else if 1 <= from && from <= to && to <= 25 {
+            days.extend(from..=to);
+        } else {
+            return Err(format!("`{}` is not within 1-25", item));
+        }
+    }
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+/// Parses the input of `day` and solves the selected `parts`, timing each phase separately.
+pub fn run_day(
+    year: u32,
+    day: u32,
+    source: &InputSource,
+    parts: &[u32],
+) -> Result<DayRun, RunError> {
+    let solver = solution(year, day).ok_or(RunError::NotImplemented { day })?;
+    let advent = input(year, day, source)?;
+    let mut timings = Timings::default();
+
+    let start = Instant::now();
+    let input = solver
+        .parse_input(&advent)
+        .map_err(|error| RunError::Parse {
+            day,
+            input_file: advent.input_file(),
+            error,
+        })?;
+    timings.parse = start.elapsed();
+
+    let mut answers = vec![];
+    for &part in parts {
+        let start = Instant::now();
+        let answer = solver.solve(part, input.as_ref());
+        match part {
+            1 => timings.part1 = start.elapsed(),
+            _ => timings.part2 = start.elapsed(),
+        }
+        if answer != Answer::Missing {
+            answers.push((part, answer))
+        }
+    }
+    Ok(DayRun {
+        year,
+        day,
+        input_file: advent.input_file(),
+        answers,
+        timings,
+    })
+}
+
+/// Renders the puzzle state of `day` for its input, `None` if the day has no visualization.
+pub fn visualize_day(
+    year: u32,
+    day: u32,
+    source: &InputSource,
+    ansi: bool,
+) -> Result<Option<String>, RunError> {
+    let (solver, input) = parse_day(year, day, source)?;
+    let rendered = solver
+        .visualize(input.as_ref())
+        .map(|renderer| renderer.ansi(ansi).to_string());
+    Ok(rendered)
+}
+
+/// The pictures `day` draws of the puzzle state for its input, empty if it draws none.
+pub fn day_frames(year: u32, day: u32, source: &InputSource) -> Result<Vec<Frame>, RunError> {
+    let (solver, input) = parse_day(year, day, source)?;
+    Ok(solver.frames(input.as_ref()))
+}
+
+/// Saves the pictures of [`day_frames`] to `path`, a single one with [`save`] unless `path` is a
+/// `.gif` file, and several with [`save_animation`]. Returns the number of saved pictures, 0 if
+/// the day draws none.
+pub fn export_day(year: u32, day: u32, source: &InputSource, path: &Path) -> Result<usize, String> {
+    let frames = day_frames(year, day, source).map_err(|e| e.to_string())?;
+    let gif = path
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("gif"));
+    match frames.as_slice() {
+        [] => return Ok(0),
+        [frame] if !gif => save(frame, path)?,
+        frames => save_animation(frames, path)?,
+    }
+    Ok(frames.len())
+}
+
+fn input(year: u32, day: u32, source: &InputSource) -> Result<AdventHelper, RunError> {
+    let advent = AdventHelper::with_source(year, day, source.clone());
+    let input_file = advent.input_file();
+    advent.load().map_err(|error| RunError::Input {
+        day,
+        input_file,
+        error: error.to_string(),
+    })
+}
+
+fn parse_day(
+    year: u32,
+    day: u32,
+    source: &InputSource,
+) -> Result<(&'static dyn Solver, Box<dyn Any>), RunError> {
+    let solver = solution(year, day).ok_or(RunError::NotImplemented { day })?;
+    let advent = input(year, day, source)?;
+    let input = solver
+        .parse_input(&advent)
+        .map_err(|error| RunError::Parse {
+            day,
+            input_file: advent.input_file(),
+            error,
+        })?;
+    Ok((solver, input))
+}
+
+/// Like [`run_day`] for each of `days`, but solving the days concurrently.
+/// The results are in the same order as `days`.
+pub fn run_days_parallel(
+    year: u32,
+    days: &[u32],
+    source: &InputSource,
+    parts: &[u32],
+) -> Vec<Result<DayRun, RunError>> {
+    days.par_iter()
+        .map(|&day| run_day(year, day, source, parts))
+        .collect()
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::{parse_days, run_day, DayRun, RunError, Timings};
+    use crate::solution::Answer;
+    use crate::util::InputSource;
+    use std::time::Duration;
+
+    #[test]
+    fn formats_records() {
+        let run = DayRun {
+            year: 2023,
+            day: 7,
+            input_file: "resources/day7.txt".to_string(),
+            answers: vec![(1, Answer::Number(42)), (2, Answer::from("a \"b\", c"))],
+            timings: Timings {
+                parse: Duration::from_micros(10),
+                part1: Duration::from_micros(20),
+                part2: Duration::from_micros(1234),
+            },
+        };
+
+        assert_eq!(
+            run.to_json(),
+            [
+                r#"{"year":2023,"day":7,"part":1,"answer":42,"input_file":"resources/day7.txt","parse_us":10,"elapsed_us":20}"#,
+                r#"{"year":2023,"day":7,"part":2,"answer":"a \"b\", c","input_file":"resources/day7.txt","parse_us":10,"elapsed_us":1234}"#
+            ]
+            .join("\n")
+        );
+        assert_eq!(
+            run.to_text(true),
+            "Day 7, Part 1: winnings: 42\nDay 7, Part 2: winnings: a \"b\", c\nTime: ".to_string()
+                + &run.timings.to_string()
+        );
+        assert_eq!(
+            run.to_csv(),
+            "2023,7,1,42,resources/day7.txt,10,20\n2023,7,2,\"a \"\"b\"\", c\",resources/day7.txt,10,1234"
+        );
+    }
+
+    #[test]
+    fn parses_day_selections() {
+        assert_eq!(parse_days("5"), Ok(vec![5]));
+        assert_eq!(parse_days("12,5,7,10-12"), Ok(vec![5, 7, 10, 11, 12]));
+        assert_eq!(parse_days("0").map(|days| days.len()), Ok(25));
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("12-10").is_err());
+        assert!(parse_days("five").is_err());
+    }
+
+    #[test]
+    fn reports_missing_input() {
+        let missing = run_day(2023, 3, &InputSource::Example(9), &[1, 2]).unwrap_err();
+
+        assert!(matches!(
+            &missing,
+            RunError::Input { day: 3, input_file, .. } if input_file == "resources/2023/day3.test9.txt"
+        ));
+        assert!(missing
+            .to_string()
+            .starts_with("Day 3: could not read resources/2023/day3.test9.txt, "));
+
+        let directory = InputSource::File("resources".to_string());
+        assert!(matches!(
+            run_day(2023, 3, &directory, &[1, 2]),
+            Err(RunError::Input { day: 3, .. })
+        ));
+    }
+}
@@ -0,0 +1,226 @@
+use std::fs;
+use std::path::Path;
+
+const TEMPLATE: &str = r#"use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, ParseError};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+
+    const PART1: &'static str = "{}";
+    const PART2: &'static str = "{}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.parse_from_strings()
+    }
+
+    fn part1(&self, lines: &Self::Input) -> Answer {
+        lines.len().into()
+    }
+}
+"#;
+
+const YEAR_TEMPLATE: &str = r#"//! Solutions of Advent of Code {year}.
+
+use crate::solution::Solver;
+
+/// The solution of day `n` is at index `n - 1`.
+pub const SOLUTIONS: &[&dyn Solver] = &[
+];
+"#;
+
+pub fn module_source(day: u32) -> String {
+    TEMPLATE.replace("{day}", &day.to_string())
+}
+
+/// Creates the module of a new day below `root` from a template and registers it in the module
+/// of its year, which is created and registered in [`YEARS`](crate::YEARS) for the first day of a
+/// new year. Also creates empty input files and adds commented out answer stubs.
+/// Returns the files that were created or changed.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<String>, String> {
+    let module = root.join(format!("src/year{}/day{}.rs", year, day));
+    if module.exists() {
+        return Err(format!("{} already exists", module.display()));
+    }
+    let mut changed = vec![];
+    let year_module = root.join(format!("src/year{}/mod.rs", year));
+    let year_source = if year_module.exists() {
+        read(&year_module)?
+    } else {
+        let lib = root.join("src/lib.rs");
+        let registry = root.join("src/solution.rs");
+        let lib_source = register_module(&read(&lib)?, &format!("year{}", year));
+        let entry = format!("    ({}, crate::year{}::SOLUTIONS),\n", year, year);
+        let registry_source = append_entry(&read(&registry)?, "pub const YEARS", &entry)?;
+        write(&lib, &lib_source, &mut changed)?;
+        write(&registry, &registry_source, &mut changed)?;
+        YEAR_TEMPLATE.replace("{year}", &year.to_string())
+    };
+    let year_source = register_solution(&year_source, day)?;
+    let year_source = register_module(&year_source, &format!("day{}", day));
+
+    write(&module, &module_source(day), &mut changed)?;
+    write(&year_module, &year_source, &mut changed)?;
+    let resources = root.join("resources").join(year.to_string());
+    for input in [format!("day{}.txt", day), format!("day{}.test.txt", day)] {
+        let input = resources.join(input);
+        if !input.exists() {
+            write(&input, "", &mut changed)?;
+        }
+    }
+    for answers in ["answers.txt", "answers.test.txt"] {
+        let answers = resources.join(answers);
+        let mut contents = if answers.exists() {
+            read(&answers)?
+        } else {
+            String::new()
+        };
+        if !contents.is_empty() && !contents.ends_with('\n') {
+            contents.push('\n');
+        }
+        contents.push_str(&format!("# {}.1 = \n# {}.2 = \n", day, day));
+        write(&answers, &contents, &mut changed)?;
+    }
+    Ok(changed)
+}
+
+/// Adds `pub mod <name>;` to the sorted `pub mod` declarations of `source`, or below its imports
+/// if there are none yet.
+fn register_module(source: &str, name: &str) -> String {
+    let mut lines: Vec<String> = source.lines().map(|l| l.to_string()).collect();
+    let declaration = format!("pub mod {};", name);
+    match lines.iter().position(|l| l.starts_with("pub mod ")) {
+        Some(first) => {
+            let count = lines[first..]
+                .iter()
+                .take_while(|l| l.starts_with("pub mod "))
+                .count();
+            lines.insert(first + count, declaration);
+            lines[first..=first + count].sort();
+        }
+        None => {
+            let imports = lines.iter().rposition(|l| l.starts_with("use "));
+            let at = imports.map_or(0, |i| i + 1);
+            lines.splice(at..at, [String::new(), declaration]);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Appends the solution of `day` to the `SOLUTIONS` of a year, which must already contain all
+/// the days before it.
+fn register_solution(year_source: &str, day: u32) -> Result<String, String> {
+    let start = year_source
+        .find("pub const SOLUTIONS")
+        .ok_or("Could not find SOLUTIONS")?;
+    let registered = year_source[start..].matches("::Day").count() as u32;
+    if registered + 1 != day {
+        return Err(format!(
+            "Days 1-{} are registered, the next one must be day {}",
+            registered,
+            registered + 1
+        ));
+    }
+    let entry = format!("    &day{}::Day{},\n", day, day);
+    append_entry(year_source, "pub const SOLUTIONS", &entry)
+}
+
+/// Inserts `entry` at the end of the array that follows `item` in `source`. The array may have
+/// been put on a single line by rustfmt, which is undone by the next run of rustfmt.
+fn append_entry(source: &str, item: &str, entry: &str) -> Result<String, String> {
+    let start = source
+        .find(item)
+        .ok_or(format!("Could not find {}", item))?;
+    let end = start
+        + source[start..]
+            .find("];")
+            .ok_or(format!("Could not find the end of {}", item))?;
+    let head = source[..end].trim_end();
+    let separator = if head.ends_with(['[', ',']) { "" } else { "," };
+    Ok(format!(
+        "{}{}\n{}{}",
+        head,
+        separator,
+        entry,
+        &source[end..]
+    ))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str, changed: &mut Vec<String>) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+    }
+    fs::write(path, contents).map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+    changed.push(path.display().to_string());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::new_day;
+    use std::fs;
+
+    #[test]
+    fn scaffolds_the_next_day() {
+        let root = tempfile::tempdir().unwrap();
+        let path = |file: &str| root.path().join(file);
+        fs::create_dir_all(path("src")).unwrap();
+        fs::create_dir_all(path("resources/2024")).unwrap();
+        fs::write(
+            path("src/lib.rs"),
+            "pub mod util;\npub mod year2023;\n\npub use x;\n",
+        )
+        .unwrap();
+        fs::write(
+            path("src/solution.rs"),
+            "pub const YEARS: &[(u32, &[&dyn Solver])] = &[(2023, crate::year2023::SOLUTIONS)];\n",
+        )
+        .unwrap();
+        fs::write(path("resources/2024/answers.txt"), "1.1 = 42").unwrap();
+
+        assert_eq!(new_day(root.path(), 2024, 1).unwrap().len(), 8);
+        assert_eq!(new_day(root.path(), 2024, 2).unwrap().len(), 6);
+
+        let read = |file: &str| fs::read_to_string(path(file)).unwrap();
+        assert!(read("src/year2024/day2.rs").contains("impl Solution for Day2 {"));
+        assert_eq!(
+            read("src/lib.rs"),
+            "pub mod util;\npub mod year2023;\npub mod year2024;\n\npub use x;\n"
+        );
+        assert!(read("src/solution.rs")
+            .ends_with("SOLUTIONS),\n    (2024, crate::year2024::SOLUTIONS),\n];\n"));
+        assert_eq!(
+            read("src/year2024/mod.rs"),
+            [
+                "//! Solutions of Advent of Code 2024.",
+                "",
+                "use crate::solution::Solver;",
+                "",
+                "pub mod day1;",
+                "pub mod day2;",
+                "",
+                "/// The solution of day `n` is at index `n - 1`.",
+                "pub const SOLUTIONS: &[&dyn Solver] = &[",
+                "    &day1::Day1,",
+                "    &day2::Day2,",
+                "];",
+                "",
+            ]
+            .join("\n")
+        );
+        assert_eq!(read("resources/2024/day2.test.txt"), "");
+        assert_eq!(
+            read("resources/2024/answers.txt"),
+            "1.1 = 42\n# 1.1 = \n# 1.2 = \n# 2.1 = \n# 2.2 = \n"
+        );
+        assert!(new_day(root.path(), 2024, 2).is_err());
+        assert!(new_day(root.path(), 2024, 4).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::util::plot::Frame;
+use crate::util::{AdventHelper, ParseError, Renderer};
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// The puzzle has no such part (e.g. the second part of day 25)
+    Missing,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Missing => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, u32, i64, u64, usize, i128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// A solver for a single day. The input is parsed once and then shared by both parts.
+pub trait Solution {
+    type Input;
+
+    /// Template used when printing the answer to part 1, `{}` is replaced by the answer
+    const PART1: &'static str = "{}";
+    /// Template used when printing the answer to part 2, `{}` is replaced by the answer
+    const PART2: &'static str = "{}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError>;
+
+    fn part1(&self, input: &Self::Input) -> Answer;
+
+    fn part2(&self, _input: &Self::Input) -> Answer {
+        Answer::Missing
+    }
+
+    /// A picture of the puzzle state for `--visualize`, if the day has one
+    fn visualize<'a>(&self, _input: &'a Self::Input) -> Option<Renderer<'a>> {
+        None
+    }
+
+    /// Pictures of the puzzle state for `--export`, more than one is saved as an animation
+    fn frames(&self, _input: &Self::Input) -> Vec<Frame> {
+        vec![]
+    }
+}
+
+/// Object safe version of [`Solution`], so that solutions of different days can be stored
+/// side by side in [`YEARS`].
+pub trait Solver: Sync {
+    fn parse_input(&self, advent: &AdventHelper) -> Result<Box<dyn Any>, ParseError>;
+
+    fn solve(&self, part: u32, input: &dyn Any) -> Answer;
+
+    fn template(&self, part: u32) -> &'static str;
+
+    fn visualize<'a>(&self, input: &'a dyn Any) -> Option<Renderer<'a>>;
+
+    fn frames(&self, input: &dyn Any) -> Vec<Frame>;
+}
+
+impl<S> Solver for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn parse_input(&self, advent: &AdventHelper) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(self.parse(advent)?))
+    }
+
+    fn solve(&self, part: u32, input: &dyn Any) -> Answer {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was parsed by a different solution");
+        match part {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            other => panic!("There is no part {}", other),
+        }
+    }
+
+    fn template(&self, part: u32) -> &'static str {
+        match part {
+            1 => S::PART1,
+            2 => S::PART2,
+            other => panic!("There is no part {}", other),
+        }
+    }
+
+    fn visualize<'a>(&self, input: &'a dyn Any) -> Option<Renderer<'a>> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was parsed by a different solution");
+        Solution::visualize(self, input)
+    }
+
+    fn frames(&self, input: &dyn Any) -> Vec<Frame> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was parsed by a different solution");
+        Solution::frames(self, input)
+    }
+}
+
+/// The solutions of every year, see for example [`year2023::SOLUTIONS`](crate::year2023::SOLUTIONS).
+pub const YEARS: &[(u32, &[&dyn Solver])] = &[(2023, crate::year2023::SOLUTIONS)];
+
+pub fn years() -> impl Iterator<Item = u32> {
+    YEARS.iter().map(|(year, _)| *year)
+}
+
+pub fn solution(year: u32, day: u32) -> Option<&'static dyn Solver> {
+    let (_, solutions) = YEARS.iter().find(|(y, _)| *y == year)?;
+    let idx = (day as usize).checked_sub(1)?;
+    solutions.get(idx).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{solution, Answer};
+    use crate::util::AdventHelper;
+
+    #[test]
+    fn solves_both_parts_of_a_day() {
+        let solver = solution(2023, 3).expect("Day 3 is registered");
+        let advent = AdventHelper::new(2023, 3).test().load().unwrap();
+
+        let input = solver.parse_input(&advent).unwrap();
+
+        assert_eq!(solver.solve(1, input.as_ref()), Answer::Number(4361));
+        assert_eq!(solver.solve(2, input.as_ref()), Answer::Number(467835));
+    }
+
+    #[test]
+    fn unknown_days_are_not_registered() {
+        assert!(solution(2023, 0).is_none());
+        assert!(solution(2023, 26).is_none());
+        assert!(solution(1999, 1).is_none());
+    }
+}
@@ -0,0 +1,372 @@
+use crate::client::Client;
+use crate::runner::run_day;
+use crate::util::InputSource;
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Where `submit` keeps its attempts, ignored by git like the session in `.env`
+pub const HISTORY_FILE: &str = "resources/submissions.txt";
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the previous attempt, with the seconds left to wait if known
+    Wait(Option<u64>),
+    /// The part was already solved before
+    AlreadyCompleted,
+}
+
+impl Outcome {
+    pub fn from_response(html: &str) -> Result<Outcome, String> {
+        if html.contains("That's the right answer") {
+            Ok(Outcome::Right)
+        } else if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                Ok(Outcome::TooHigh)
+            } else if html.contains("your answer is too low") {
+                Ok(Outcome::TooLow)
+            } else {
+                Ok(Outcome::Wrong)
+            }
+        } else if html.contains("You gave an answer too recently") {
+            let re = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+            let seconds = re.captures(html).map(|c| {
+                let minutes = c.get(1).map_or(0, |m| m.as_str().parse::<u64>().unwrap());
+                minutes * 60 + c[2].parse::<u64>().unwrap()
+            });
+            Ok(Outcome::Wait(seconds))
+        } else if html.contains("You don't seem to be solving the right level") {
+            Ok(Outcome::AlreadyCompleted)
+        } else {
+            Err("Could not understand the response".to_string())
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Right => write!(f, "right"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wait(Some(seconds)) => write!(f, "wait {}", seconds),
+            Outcome::Wait(None) => write!(f, "wait"),
+            Outcome::AlreadyCompleted => write!(f, "already completed"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "right" => Ok(Outcome::Right),
+            "wrong" => Ok(Outcome::Wrong),
+            "too high" => Ok(Outcome::TooHigh),
+            "too low" => Ok(Outcome::TooLow),
+            "wait" => Ok(Outcome::Wait(None)),
+            "already completed" => Ok(Outcome::AlreadyCompleted),
+            _ => s
+                .strip_prefix("wait ")
+                .and_then(|seconds| seconds.parse().ok())
+                .map(|seconds| Outcome::Wait(Some(seconds)))
+                .ok_or(format!("Unknown outcome `{}`", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// Seconds since the Unix epoch
+    pub time: u64,
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Every answer submitted so far, stored as `time year.day.part = answer -> outcome` lines.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn load(file_path: &str) -> Result<History, String> {
+        if !Path::new(file_path).exists() {
+            return Ok(History::default());
+        }
+        let contents = fs::read_to_string(file_path)
+            .map_err(|e| format!("Could not read {}: {}", file_path, e))?;
+        History::parse(&contents).map_err(|e| format!("{}: {}", file_path, e))
+    }
+
+    pub fn parse(contents: &str) -> Result<History, String> {
+        let mut attempts = vec![];
+        for (line_number, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let attempt = parse_line(line).ok_or_else(|| {
+                format!("Malformed submission on line {}: {}", line_number + 1, line)
+            })?;
+            attempts.push(attempt);
+        }
+        Ok(History { attempts })
+    }
+
+    /// Writes the history, creating its directory if needed
+    pub fn save(&self, file_path: &str) -> Result<(), String> {
+        let write = || {
+            if let Some(dir) = Path::new(file_path).parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(file_path, self.to_string())
+        };
+        write().map_err(|e| format!("Could not write {}: {}", file_path, e))
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt)
+    }
+
+    pub fn attempts(&self, year: u32, day: u32, part: u32) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| (a.year, a.day, a.part) == (year, day, part))
+    }
+
+    /// Refuses answers that cannot be right given the earlier attempts, or that would be
+    /// submitted before the waiting time of the last attempt has passed.
+    pub fn check(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), String> {
+        if let Some(Attempt {
+            time,
+            outcome: Outcome::Wait(Some(seconds)),
+            ..
+        }) = self.attempts.last()
+        {
+            if now < time + seconds {
+                return Err(format!("Wait another {}s", time + seconds - now));
+            }
+        }
+        let number = answer.parse::<i128>().ok();
+        for attempt in self.attempts(year, day, part) {
+            let previous = attempt.answer.parse::<i128>().ok();
+            match (&attempt.outcome, number, previous) {
+                (Outcome::Right, _, _) => {
+                    return Err(format!("Already solved, the answer is {}", attempt.answer))
+                }
+                (outcome, _, _) if outcome.is_wrong() && attempt.answer == answer => {
+                    return Err(format!("{} was already submitted: {}", answer, outcome))
+                }
+                (Outcome::TooHigh, Some(n), Some(high)) if n >= high => {
+                    return Err(format!("{} is too high, {} already was", answer, high))
+                }
+                (Outcome::TooLow, Some(n), Some(low)) if n <= low => {
+                    return Err(format!("{} is too low, {} already was", answer, low))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for a in &self.attempts {
+            writeln!(
+                f,
+                "{} {}.{}.{} = {} -> {}",
+                a.time, a.year, a.day, a.part, a.answer, a.outcome
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Submits `answer`, or the answer computed from the puzzle input when it is `None`, unless the
+/// earlier attempts in `history` rule it out. The attempt is recorded in `history`.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: Option<String>,
+) -> Result<Attempt, String> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => computed_answer(year, day, part)?,
+    };
+    if let Err(e) = history.check(year, day, part, &answer, now()) {
+        return Err(format!("Not submitting {}: {}", answer, e));
+    }
+    let outcome = client.submit_answer(year, day, part, &answer)?;
+    let attempt = Attempt {
+        time: now(),
+        year,
+        day,
+        part,
+        answer,
+        outcome,
+    };
+    history.record(attempt.clone());
+    Ok(attempt)
+}
+
+fn computed_answer(year: u32, day: u32, part: u32) -> Result<String, String> {
+    let day_run = run_day(year, day, &InputSource::Puzzle, &[part]).map_err(|e| e.to_string())?;
+    match day_run.answers.first() {
+        Some((_, answer)) => Ok(answer.to_string()),
+        None => Err(format!("Day {} has no answer for part {}", day, part)),
+    }
+}
+
+/// Seconds since the Unix epoch
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+fn parse_line(line: &str) -> Option<Attempt> {
+    let (time, rest) = line.split_once(' ')?;
+    let (key, rest) = rest.split_once('=')?;
+    let (answer, outcome) = rest.rsplit_once("->")?;
+    let mut key = key.trim().split('.');
+    let attempt = Attempt {
+        time: time.parse().ok()?,
+        year: key.next()?.parse().ok()?,
+        day: key.next()?.parse().ok()?,
+        part: key.next()?.parse().ok()?,
+        answer: answer.trim().to_string(),
+        outcome: outcome.trim().parse().ok()?,
+    };
+    key.next().is_none().then_some(attempt)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::Client;
+    use crate::submit::{submit, Attempt, History, Outcome};
+
+    #[test]
+    fn parses_responses() {
+        let response =
+            |text: &str| Outcome::from_response(&format!("<article><p>{}</p></article>", text));
+
+        assert_eq!(
+            response("That's the right answer! You are one gold star closer."),
+            Ok(Outcome::Right)
+        );
+        assert_eq!(response("That's not the right answer."), Ok(Outcome::Wrong));
+        assert_eq!(
+            response("That's not the right answer; your answer is too high."),
+            Ok(Outcome::TooHigh)
+        );
+        assert_eq!(
+            response("That's not the right answer; your answer is too low."),
+            Ok(Outcome::TooLow)
+        );
+        assert_eq!(
+            response("You gave an answer too recently. You have 1m 5s left to wait."),
+            Ok(Outcome::Wait(Some(65)))
+        );
+        assert!(response("Something else").is_err());
+    }
+
+    #[test]
+    fn refuses_impossible_answers() {
+        let attempt = |time: u64, answer: &str, outcome: Outcome| Attempt {
+            time,
+            year: 2023,
+            day: 3,
+            part: 1,
+            answer: answer.to_string(),
+            outcome,
+        };
+        let mut history = History::default();
+        history.record(attempt(100, "50", Outcome::TooHigh));
+        history.record(attempt(110, "10", Outcome::TooLow));
+        history.record(attempt(120, "20", Outcome::Wrong));
+        history.record(attempt(130, "30", Outcome::Wait(Some(60))));
+
+        let history = History::parse(&history.to_string()).unwrap();
+
+        assert!(history.check(2023, 3, 1, "30", 150).is_err());
+        assert!(history.check(2023, 3, 1, "20", 200).is_err());
+        assert!(history.check(2023, 3, 1, "50", 200).is_err());
+        assert!(history.check(2023, 3, 1, "60", 200).is_err());
+        assert!(history.check(2023, 3, 1, "10", 200).is_err());
+        assert_eq!(history.check(2023, 3, 1, "30", 200), Ok(()));
+        assert_eq!(history.check(2023, 3, 2, "60", 200), Ok(()));
+    }
+
+    #[test]
+    fn submits_only_possible_answers() {
+        let client = Client::new("http://127.0.0.1:1", "secret");
+        let mut history = History::default();
+        history.record(Attempt {
+            time: 0,
+            year: 2023,
+            day: 3,
+            part: 1,
+            answer: "50".to_string(),
+            outcome: Outcome::TooHigh,
+        });
+
+        let refused = submit(&client, &mut history, 2023, 3, 1, Some("60".to_string()));
+
+        assert_eq!(
+            refused,
+            Err("Not submitting 60: 60 is too high, 50 already was".to_string())
+        );
+        assert_eq!(history.attempts(2023, 3, 1).count(), 1);
+    }
+
+    #[test]
+    fn saves_and_loads_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("resources/submissions.txt");
+        let file = file.to_str().unwrap();
+        let mut history = History::default();
+        history.record(Attempt {
+            time: 100,
+            year: 2023,
+            day: 3,
+            part: 2,
+            answer: "467835".to_string(),
+            outcome: Outcome::Right,
+        });
+
+        history.save(file).unwrap();
+
+        assert_eq!(History::load(file), Ok(history));
+        assert!(History::parse("100 2023.3.2 = 467835").is_err());
+    }
+}
@@ -1,112 +1,550 @@
+use itertools::traits::HomogeneousTuple;
 use itertools::{Itertools, Product};
 
 use crate::util::Dir::{Down, Left, Right, Up};
 use array2d::Array2D;
 use bit_set::{BitSet, Iter};
+use num::iter::range_inclusive;
+use num::{BigInt, Num, Signed, ToPrimitive};
+use rustc_hash::FxHashMap;
+use std::any::Any;
 use std::collections::HashSet;
+use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
+use std::hash::BuildHasher;
 use std::iter::Map;
-use std::ops::RangeInclusive;
+use std::ops::{Add, Index, IndexMut, Mul, RangeInclusive, Sub};
+use std::path::Path;
 use std::str::FromStr;
-use std::{env, fs};
+use std::sync::OnceLock;
+use std::{fs, io};
 
-pub fn parse_from_strings<T: FromStr>(file_path: &str) -> Vec<T>
+pub mod compression;
+pub mod geometry;
+pub mod plot;
+
+/// Why a line of the input could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based index of the section (see [`Section`]) containing the line, if parsed by section
+    pub section: Option<usize>,
+    /// 1-based line number within the input, once known
+    pub line: Option<usize>,
+    /// 1-based column of `text` within its line
+    pub column: usize,
+    /// The offending text
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn at_line(self, line: usize) -> Self {
+        Self {
+            line: Some(line),
+            ..self
+        }
+    }
+
+    pub fn at_section(self, section: usize) -> Self {
+        Self {
+            section: Some(section),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(section) = self.section {
+            write!(f, "section {}, ", section)?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "line {}, ", line)?;
+        }
+        write!(
+            f,
+            "column {}: {} `{}`",
+            self.column, self.message, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of the input that is being parsed. Its helpers fail with a [`ParseError`] pointing at
+/// the offending part of the line, which must be a slice of that line.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a>(pub &'a str);
+
+impl<'a> Line<'a> {
+    pub fn error(&self, at: &str, message: impl Into<String>) -> ParseError {
+        let start = self.0.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + at.len() <= self.0.len())
+            .or_else(|| self.0.find(at))
+            .unwrap_or(0);
+        ParseError {
+            section: None,
+            line: None,
+            column: self.0[..offset].chars().count() + 1,
+            text: at.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn parse<T: FromStr>(&self, s: &str) -> Result<T, ParseError> {
+        let type_name = std::any::type_name::<T>();
+        let type_name = type_name.rsplit("::").next().unwrap_or(type_name);
+        s.parse()
+            .map_err(|_| self.error(s, format!("expected {}, found", type_name)))
+    }
+
+    pub fn parse_list<T: FromStr>(
+        &self,
+        s: &'a str,
+        separator: &str,
+    ) -> Result<Vec<T>, ParseError> {
+        s.split(separator).map(|item| self.parse(item)).collect()
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        separator: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(separator)
+            .ok_or_else(|| self.error(s, format!("expected `{}` in", separator)))
+    }
+
+    /// Splits `s` into exactly as many parts as the tuple `T` has
+    pub fn split_tuple<T>(&self, s: &'a str, separator: &str) -> Result<T, ParseError>
+    where
+        T: HomogeneousTuple<Item = &'a str>,
+    {
+        s.split(separator).collect_tuple().ok_or_else(|| {
+            self.error(
+                s,
+                format!("unexpected number of `{}` separated parts in", separator),
+            )
+        })
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("expected `{}` at the start of", prefix)))
+    }
+
+    pub fn strip_suffix(&self, s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        s.strip_suffix(suffix)
+            .ok_or_else(|| self.error(s, format!("expected `{}` at the end of", suffix)))
+    }
+
+    /// Matches the whole line against `pattern`, in which `{name}` is a field that extends up to
+    /// the literal text following it in the pattern, or to the end of the line. See also [`scan!`].
+    pub fn fields(&self, pattern: &str) -> Result<Fields<'a>, ParseError> {
+        self.fields_in(self.0, pattern)
+    }
+
+    /// Like [`Line::fields`], but matches `s`, a part of the line
+    pub fn fields_in(&self, s: &'a str, pattern: &str) -> Result<Fields<'a>, ParseError> {
+        let (prefix, fields) = pattern.split_once('{').unwrap_or((pattern, ""));
+        let mut rest = self.strip_prefix(s, prefix)?;
+        let mut values = vec![];
+        let fields = if fields.is_empty() {
+            vec![]
+        } else {
+            fields.split('{').collect_vec()
+        };
+        for (i, field) in fields.iter().enumerate() {
+            let (name, literal) = field
+                .split_once('}')
+                .unwrap_or_else(|| panic!("Unclosed field in pattern `{}`", pattern));
+            let value = if i == fields.len() - 1 && literal.is_empty() {
+                rest
+            } else if literal.is_empty() {
+                panic!("Fields must be separated by text in pattern `{}`", pattern)
+            } else {
+                let end = rest
+                    .find(literal)
+                    .ok_or_else(|| self.error(rest, format!("expected `{}` in", literal)))?;
+                &rest[..end]
+            };
+            values.push((name.to_string(), value));
+            rest = &rest[value.len() + literal.len()..];
+        }
+        if !rest.is_empty() {
+            return Err(self.error(rest, "unexpected text"));
+        }
+        Ok(Fields {
+            line: *self,
+            values,
+        })
+    }
+}
+
+/// The fields of a [`Line`] matched by [`Line::fields`].
+#[derive(Debug, Clone)]
+pub struct Fields<'a> {
+    line: Line<'a>,
+    values: Vec<(String, &'a str)>,
+}
+
+impl<'a> Fields<'a> {
+    pub fn str(&self, name: &str) -> &'a str {
+        self.values
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| *value)
+            .unwrap_or_else(|| panic!("There is no field `{}` in the pattern", name))
+    }
+
+    pub fn get<T: FromField<'a>>(&self, name: &str) -> Result<T, ParseError> {
+        T::from_field(self.line, self.str(name))
+    }
+}
+
+/// Conversion of a field of a [`Line`], numbers may be padded with whitespace.
+pub trait FromField<'a>: Sized {
+    fn from_field(line: Line<'a>, field: &'a str) -> Result<Self, ParseError>;
+}
+
+impl<'a> FromField<'a> for &'a str {
+    fn from_field(_line: Line<'a>, field: &'a str) -> Result<Self, ParseError> {
+        Ok(field)
+    }
+}
+
+impl<'a> FromField<'a> for String {
+    fn from_field(_line: Line<'a>, field: &'a str) -> Result<Self, ParseError> {
+        Ok(field.to_string())
+    }
+}
+
+macro_rules! from_field_via_from_str {
+    ($($t:ty),*) => {
+        $(
+            impl<'a> FromField<'a> for $t {
+                fn from_field(line: Line<'a>, field: &'a str) -> Result<Self, ParseError> {
+                    line.parse(field.trim())
+                }
+            }
+        )*
+    };
+}
+
+from_field_via_from_str!(char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f64);
+
+/// Matches a [`Line`], or a part of it, against a pattern and converts its fields to the given
+/// types, e.g. `let (id, rounds) = scan!(line, "Game {id}: {rounds}", id: u32, rounds: &str)?;`
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $pattern:literal, $($name:ident: $t:ty),+ $(,)?) => {
+        $line.fields($pattern).and_then(|fields| {
+            Ok(($(fields.get::<$t>(stringify!($name))?,)+))
+        })
+    };
+    ($line:expr, $s:expr, $pattern:literal, $($name:ident: $t:ty),+ $(,)?) => {
+        $line.fields_in($s, $pattern).and_then(|fields| {
+            Ok(($(fields.get::<$t>(stringify!($name))?,)+))
+        })
+    };
+}
+
+/// Parses every line of `contents`, attaching the line number to errors. Errors of other types
+/// than [`ParseError`] are reported for the line as a whole.
+pub fn parse_lines<T: FromStr>(contents: &str) -> Result<Vec<T>, ParseError>
 where
-    <T as FromStr>::Err: Debug,
+    <T as FromStr>::Err: Display + 'static,
 {
-    let contents = fs::read_to_string(file_path).expect("File does not exists");
-    contents.lines().map(|s| s.parse().unwrap()).collect()
+    contents
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+fn parse_line<T: FromStr>(line: &str) -> Result<T, ParseError>
+where
+    <T as FromStr>::Err: Display + 'static,
+{
+    line.parse().map_err(
+        |e: T::Err| match (&e as &dyn Any).downcast_ref::<ParseError>() {
+            Some(error) => error.clone(),
+            None => Line(line).error(line, e.to_string()),
+        },
+    )
+}
+
+/// A block of the input separated from the others by empty lines, optionally starting with a
+/// header such as `seed-to-soil map:` or `seeds: 79 14 55 13`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// 0-based index among all sections of the input
+    pub index: usize,
+    /// The header without the colon, only for sections read with [`AdventHelper::headed_sections`]
+    pub header: Option<String>,
+    /// The lines after the header, including any text following the header on its line
+    pub lines: Vec<String>,
+    line_numbers: Vec<usize>,
+    /// Characters before each line on its line of the input, only non-zero after a header
+    offsets: Vec<usize>,
+}
+
+impl Section {
+    /// 1-based line number of the `i`-th line within the whole input
+    pub fn line_number(&self, i: usize) -> usize {
+        self.line_numbers[i]
+    }
+
+    pub fn parse_lines_with<T>(
+        &self,
+        mut parser: impl FnMut(Line) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                parser(Line(line)).map_err(|e| {
+                    let column = e.column + self.offsets[i];
+                    ParseError { column, ..e }.at_line(self.line_number(i))
+                })
+            })
+            .collect()
+    }
+
+    pub fn parse_lines<T: FromStr>(&self) -> Result<Vec<T>, ParseError>
+    where
+        <T as FromStr>::Err: Display + 'static,
+    {
+        self.parse_lines_with(|line| parse_line(line.0))
+    }
+
+    pub fn grid(&self) -> Result<Grid<char>, ParseError> {
+        Grid::try_from_rows(self.lines.iter().map(|s| s.chars().collect_vec()).collect()).map_err(
+            |e| {
+                let line = self.line_number(e.line.unwrap_or(1) - 1);
+                e.at_line(line)
+            },
+        )
+    }
 }
 
-pub fn parse_strings(file_path: &str) -> Vec<String> {
-    let contents = fs::read_to_string(file_path).expect("File does not exists");
-    contents.lines().map(|s| s.to_string()).collect()
+fn sections(contents: &str, headed: bool) -> Vec<Section> {
+    let mut sections: Vec<Section> = vec![];
+    let mut previous_empty = true;
+    for (i, line) in contents.lines().enumerate() {
+        if line.is_empty() {
+            previous_empty = true;
+            continue;
+        }
+        if previous_empty {
+            previous_empty = false;
+            let mut section = Section {
+                index: sections.len(),
+                header: None,
+                lines: vec![],
+                line_numbers: vec![],
+                offsets: vec![],
+            };
+            if let Some((header, rest)) = line.split_once(':').filter(|_| headed) {
+                section.header = Some(header.to_string());
+                let text = rest.trim_start();
+                if !text.trim_end().is_empty() {
+                    let offset = line[..line.len() - text.len()].chars().count();
+                    section.lines.push(text.trim_end().to_string());
+                    section.line_numbers.push(i + 1);
+                    section.offsets.push(offset);
+                }
+                sections.push(section);
+                continue;
+            }
+            sections.push(section);
+        }
+        let section = sections.last_mut().unwrap();
+        section.lines.push(line.to_string());
+        section.line_numbers.push(i + 1);
+        section.offsets.push(0);
+    }
+    sections
 }
 
-pub fn day(file_name: &str) -> &str {
-    file_name
-        .strip_prefix("src/day")
-        .unwrap()
-        .strip_suffix(".rs")
-        .unwrap()
+fn parse_sections<T>(
+    sections: &[Section],
+    mut parser: impl FnMut(&Section) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    sections
+        .iter()
+        .map(|section| parser(section).map_err(|e| e.at_section(section.index + 1)))
+        .collect()
 }
 
-pub(crate) struct AdventHelper {
+/// Where the input of a day is read from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// `resources/<year>/day<day>.txt`
+    #[default]
+    Puzzle,
+    /// The n-th example, `resources/<year>/day<day>.test.txt` for the first one and
+    /// `resources/<year>/day<day>.test<n>.txt` for the others
+    Example(u32),
+    File(String),
+    Stdin,
+}
+
+/// Standard input is read once and shared by every day that asks for it.
+fn stdin_contents() -> io::Result<&'static str> {
+    static STDIN: OnceLock<Result<String, io::ErrorKind>> = OnceLock::new();
+    match STDIN.get_or_init(|| io::read_to_string(io::stdin()).map_err(|e| e.kind())) {
+        Ok(contents) => Ok(contents),
+        Err(kind) => Err(io::Error::from(*kind)),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AdventHelper {
+    year: u32,
     day: u32,
-    suffix: String,
+    source: InputSource,
+    contents: Option<String>,
 }
 
 impl AdventHelper {
-    pub fn from_file_name(file_name: &str) -> Self {
-        let it = Self {
-            day: day(file_name).parse().unwrap(),
-            suffix: "".to_string(),
-        };
+    pub fn new(year: u32, day: u32) -> Self {
+        Self::with_source(year, day, InputSource::Puzzle)
+    }
 
-        if env::var("TEST").is_ok() {
-            it.test()
-        } else {
-            it
+    pub fn with_source(year: u32, day: u32, source: InputSource) -> Self {
+        Self {
+            year,
+            day,
+            source,
+            contents: None,
         }
     }
 
-    #[allow(dead_code)]
     pub fn test(&self) -> Self {
-        Self {
-            day: self.day,
-            suffix: ".test".to_string(),
-        }
+        self.example(1)
+    }
+
+    pub fn example(&self, n: u32) -> Self {
+        Self::with_source(self.year, self.day, InputSource::Example(n))
     }
 
     pub fn input_file(&self) -> String {
-        format!("resources/day{}{}.txt", self.day, self.suffix)
+        match &self.source {
+            InputSource::Puzzle => format!("{}/day{}.txt", self.resources(), self.day),
+            InputSource::Example(1) => format!("{}/day{}.test.txt", self.resources(), self.day),
+            InputSource::Example(n) => {
+                format!("{}/day{}.test{}.txt", self.resources(), self.day, n)
+            }
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => "-".to_string(),
+        }
     }
 
-    pub fn part1<T: Display>(&self, template: &str, output: T) {
-        self.part(1, template, output)
+    pub fn has_input(&self) -> bool {
+        self.source == InputSource::Stdin || Path::new(&self.input_file()).exists()
     }
-    pub fn part2<T: Display>(&self, template: &str, output: T) {
-        self.part(2, template, output)
+
+    /// The expected answers for the input, only known for puzzle inputs and examples
+    pub fn answers_file(&self) -> Option<String> {
+        match &self.source {
+            InputSource::Puzzle => Some(format!("{}/answers.txt", self.resources())),
+            InputSource::Example(1) => Some(format!("{}/answers.test.txt", self.resources())),
+            InputSource::Example(n) => Some(format!("{}/answers.test{}.txt", self.resources(), n)),
+            InputSource::File(_) | InputSource::Stdin => None,
+        }
     }
 
-    fn part<T: Display>(&self, part_num: u32, template: &str, output: T) {
-        let actual_output = template.replace("{}", &output.to_string());
-        println!("Day {}, Part {}: {}", self.day, part_num, actual_output)
+    fn resources(&self) -> String {
+        format!("resources/{}", self.year)
     }
 
-    pub fn parse_from_strings<T: FromStr>(&self) -> Vec<T>
-    where
-        <T as FromStr>::Err: Debug,
-    {
-        parse_from_strings(&self.input_file())
+    pub fn read_input(&self) -> io::Result<String> {
+        match (&self.contents, &self.source) {
+            (Some(contents), _) => Ok(contents.clone()),
+            (None, InputSource::Stdin) => stdin_contents().map(str::to_string),
+            (None, _) => fs::read_to_string(self.input_file()),
+        }
+    }
+
+    /// Reads the input once, so that the parse methods below cannot fail to read it
+    pub fn load(mut self) -> io::Result<Self> {
+        self.contents = Some(self.read_input()?);
+        Ok(self)
+    }
+
+    fn contents(&self) -> &str {
+        self.contents
+            .as_deref()
+            .expect("The input should be loaded before it is parsed")
     }
 
-    pub fn parse_sequences_from_strings<T: FromStr>(&self, separator: &str) -> Vec<Vec<T>>
+    pub fn parse_from_strings<T: FromStr>(&self) -> Result<Vec<T>, ParseError>
     where
-        <T as FromStr>::Err: Debug,
+        <T as FromStr>::Err: Display + 'static,
     {
-        let lines: Vec<String> = parse_from_strings(&self.input_file());
-        lines
-            .iter()
-            .map(|line| {
-                line.split(separator)
-                    .map(|x| x.parse().unwrap())
-                    .collect_vec()
+        parse_lines(self.contents())
+    }
+
+    pub fn parse_sequences_from_strings<T: FromStr>(
+        &self,
+        separator: &str,
+    ) -> Result<Vec<Vec<T>>, ParseError> {
+        self.contents()
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let line = Line(line);
+                line.parse_list(line.0, separator)
+                    .map_err(|e| e.at_line(i + 1))
             })
-            .collect_vec()
+            .collect()
+    }
+
+    /// The blocks of the input that are separated by empty lines
+    pub fn sections(&self) -> Vec<Section> {
+        sections(self.contents(), false)
+    }
+
+    /// Like [`AdventHelper::sections`], where the first line of each section is a header that
+    /// ends with a colon
+    pub fn headed_sections(&self) -> Vec<Section> {
+        sections(self.contents(), true)
+    }
+
+    /// Parses each of [`AdventHelper::sections`], attaching the section to errors
+    pub fn parse_sections<T>(
+        &self,
+        parser: impl FnMut(&Section) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        parse_sections(&self.sections(), parser)
+    }
+
+    /// Parses each of [`AdventHelper::headed_sections`], attaching the section to errors
+    pub fn parse_headed_sections<T>(
+        &self,
+        parser: impl FnMut(&Section) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        parse_sections(&self.headed_sections(), parser)
+    }
+
+    fn lines(&self) -> Vec<String> {
+        self.contents().lines().map(|s| s.to_string()).collect()
     }
 
     pub fn parse_grid(&self) -> Vec<Vec<char>> {
-        let lines: Vec<String> = parse_from_strings(&self.input_file());
+        let lines = self.lines();
         lines.iter().map(|s| s.chars().collect_vec()).collect_vec()
     }
 
-    pub fn parse_grid_2d(&self) -> Array2D<char> {
-        Array2D::from_rows(&self.parse_grid()).unwrap()
+    pub fn grid(&self) -> Result<Grid<char>, ParseError> {
+        Grid::try_from_rows(self.parse_grid())
     }
 
     pub fn parse_from_grid(&self) -> Array2D<i32> {
-        let lines: Vec<String> = parse_from_strings(&self.input_file());
+        let lines = self.lines();
         let vec: Vec<Vec<i32>> = lines
             .iter()
             .map(|s| {
@@ -119,13 +557,15 @@ impl AdventHelper {
     }
 }
 
+/// A point on a 2D grid, `i32` by default, with `y` growing downwards. Use a wider integer type
+/// such as `i64` or `BigInt` for puzzles whose coordinates would overflow.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
-pub struct Point {
-    pub(crate) x: i32,
-    pub(crate) y: i32,
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Display for Point {
+impl<T: Display> Display for Point<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "<{}, {}>", self.x, self.y)
     }
@@ -135,9 +575,23 @@ pub trait IntoUnsafe<T>: Sized {
     fn into_unsafe(self) -> T;
 }
 
-impl IntoUnsafe<i32> for usize {
-    fn into_unsafe(self) -> i32 {
-        self as i32
+macro_rules! into_unsafe {
+    ($($from:ty => $to:ty),*) => {
+        $(
+            impl IntoUnsafe<$to> for $from {
+                fn into_unsafe(self) -> $to {
+                    self as $to
+                }
+            }
+        )*
+    };
+}
+
+into_unsafe!(usize => i32, usize => i64, usize => i128, i32 => i64, i32 => i128, i64 => i128);
+
+impl IntoUnsafe<BigInt> for usize {
+    fn into_unsafe(self) -> BigInt {
+        BigInt::from(self)
     }
 }
 
@@ -148,42 +602,61 @@ impl<T> IntoUnsafe<T> for T {
 }
 
 impl Point {
-    pub(crate) fn new(x: impl IntoUnsafe<i32>, y: impl IntoUnsafe<i32>) -> Point {
+    pub fn new(x: impl IntoUnsafe<i32>, y: impl IntoUnsafe<i32>) -> Point {
         Point {
             x: x.into_unsafe(),
             y: y.into_unsafe(),
         }
     }
 
-    pub fn up(&self) -> Point {
+    pub fn in_bounds(&self, b: &Bounds) -> bool {
+        b.contains(self)
+    }
+
+    pub fn bounds<H>(col: &HashSet<Point, H>) -> Bounds {
+        let min_x = col.iter().map(|p| p.x).min().unwrap();
+        let max_x = col.iter().map(|p| p.x).max().unwrap();
+        let min_y = col.iter().map(|p| p.y).min().unwrap();
+        let max_y = col.iter().map(|p| p.y).max().unwrap();
+        Bounds {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        }
+    }
+}
+
+impl<T: Clone + Num> Point<T> {
+    pub fn up(&self) -> Point<T> {
         Point {
-            x: self.x,
-            y: self.y - 1,
+            x: self.x.clone(),
+            y: self.y.clone() - T::one(),
         }
     }
 
-    pub fn down(&self) -> Point {
+    pub fn down(&self) -> Point<T> {
         Point {
-            x: self.x,
-            y: self.y + 1,
+            x: self.x.clone(),
+            y: self.y.clone() + T::one(),
         }
     }
 
-    pub fn left(&self) -> Point {
+    pub fn left(&self) -> Point<T> {
         Point {
-            x: self.x - 1,
-            y: self.y,
+            x: self.x.clone() - T::one(),
+            y: self.y.clone(),
         }
     }
 
-    pub fn right(&self) -> Point {
+    pub fn right(&self) -> Point<T> {
         Point {
-            x: self.x + 1,
-            y: self.y,
+            x: self.x.clone() + T::one(),
+            y: self.y.clone(),
         }
     }
 
-    pub fn mv(&self, d: Dir) -> Point {
+    pub fn mv(&self, d: Dir) -> Point<T> {
         match d {
             Up => self.up(),
             Right => self.right(),
@@ -192,38 +665,274 @@ impl Point {
         }
     }
 
-    pub fn mv_mulitple(&self, d: Dir, n: usize) -> Point {
-        let n = n as i32;
+    pub fn mv_mulitple(&self, d: Dir, n: impl IntoUnsafe<T>) -> Point<T> {
+        let n = n.into_unsafe();
+        let (x, y) = (self.x.clone(), self.y.clone());
         match d {
-            Up => Point::new(self.x, self.y - n),
-            Right => Point::new(self.x + n, self.y),
-            Down => Point::new(self.x, self.y + n),
-            Left => Point::new(self.x - n, self.y),
+            Up => Point { x, y: y - n },
+            Right => Point { x: x + n, y },
+            Down => Point { x, y: y + n },
+            Left => Point { x: x - n, y },
         }
     }
 
-    pub fn neighbours(&self) -> Vec<Point> {
+    pub fn neighbours(&self) -> Vec<Point<T>> {
         vec![self.up(), self.down(), self.left(), self.right()]
     }
 
-    pub fn in_bounds(&self, b: &Bounds) -> bool {
-        b.contains(self)
+    pub fn mv8(&self, d: Dir8) -> Point<T> {
+        match d {
+            Dir8::Up => self.up(),
+            Dir8::UpRight => self.up().right(),
+            Dir8::Right => self.right(),
+            Dir8::DownRight => self.down().right(),
+            Dir8::Down => self.down(),
+            Dir8::DownLeft => self.down().left(),
+            Dir8::Left => self.left(),
+            Dir8::UpLeft => self.up().left(),
+        }
     }
 
-    pub fn bounds<H>(col: &HashSet<Point, H>) -> Bounds {
-        let min_x = col.iter().map(|p| p.x).min().unwrap();
-        let max_x = col.iter().map(|p| p.x).max().unwrap();
-        let min_y = col.iter().map(|p| p.y).min().unwrap();
-        let max_y = col.iter().map(|p| p.y).max().unwrap();
-        Bounds {
-            min_x,
-            max_x,
-            min_y,
-            max_y,
+    /// The orthogonal and diagonal neighbours, clockwise starting with the one above
+    pub fn neighbours8(&self) -> Vec<Point<T>> {
+        Dir8::ALL.iter().map(|d| self.mv8(*d)).collect()
+    }
+}
+
+impl<T: Clone + Signed> Point<T> {
+    /// Number of orthogonal steps between the points
+    pub fn manhattan(&self, other: &Point<T>) -> T {
+        (self.x.clone() - other.x.clone()).abs() + (self.y.clone() - other.y.clone()).abs()
+    }
+
+    /// Number of steps between the points when diagonal steps are allowed too
+    pub fn chebyshev(&self, other: &Point<T>) -> T
+    where
+        T: Ord,
+    {
+        let dx = (self.x.clone() - other.x.clone()).abs();
+        let dy = (self.y.clone() - other.y.clone()).abs();
+        dx.max(dy)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, rhs: Point<T>) -> Self::Output {
+        Point {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, rhs: Point<T>) -> Self::Output {
+        Point {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
         }
     }
 }
 
+impl<T: Clone + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point {
+            x: self.x * rhs.clone(),
+            y: self.y * rhs,
+        }
+    }
+}
+
+/// A point in 3D space, `i32` by default.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct Point3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// The difference between two [`Point3`]s, such as a velocity or a direction.
+pub type Vec3<T = i32> = Point3<T>;
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}, {}, {}>", self.x, self.y, self.z)
+    }
+}
+
+impl<T> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Clone + Num> Point3<T> {
+    pub fn dot(&self, other: &Vec3<T>) -> T {
+        self.x.clone() * other.x.clone()
+            + self.y.clone() * other.y.clone()
+            + self.z.clone() * other.z.clone()
+    }
+
+    pub fn cross(&self, other: &Vec3<T>) -> Vec3<T> {
+        let (x, y, z) = (self.x.clone(), self.y.clone(), self.z.clone());
+        let (ox, oy, oz) = (other.x.clone(), other.y.clone(), other.z.clone());
+        Point3 {
+            x: y.clone() * oz.clone() - z.clone() * oy.clone(),
+            y: z * ox.clone() - x.clone() * oz,
+            z: x * oy - y * ox,
+        }
+    }
+
+    /// The 6 points that share a face with this one
+    pub fn neighbours(&self) -> Vec<Point3<T>> {
+        self.neighbours26()
+            .into_iter()
+            .filter(|n| {
+                let same = [n.x == self.x, n.y == self.y, n.z == self.z];
+                same.iter().filter(|s| **s).count() == 2
+            })
+            .collect()
+    }
+
+    /// The 26 points that share a face, an edge or a corner with this one
+    pub fn neighbours26(&self) -> Vec<Point3<T>> {
+        let around = |v: &T| [v.clone() - T::one(), v.clone(), v.clone() + T::one()];
+        let mut neighbours = Vec::with_capacity(26);
+        for x in around(&self.x) {
+            for y in around(&self.y) {
+                for z in around(&self.z) {
+                    let n = Point3::new(x.clone(), y.clone(), z);
+                    if n != *self {
+                        neighbours.push(n);
+                    }
+                }
+            }
+        }
+        neighbours
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, rhs: Point3<T>) -> Self::Output {
+        Point3 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, rhs: Point3<T>) -> Self::Output {
+        Point3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<T: Clone + Mul<Output = T>> Mul<T> for Point3<T> {
+    type Output = Point3<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point3 {
+            x: self.x * rhs.clone(),
+            y: self.y * rhs.clone(),
+            z: self.z * rhs,
+        }
+    }
+}
+
+/// An axis-aligned box of the points from `min` to `max`, both inclusive.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Box3<T = i32> {
+    pub min: Point3<T>,
+    pub max: Point3<T>,
+}
+
+impl<T: Clone + Num + Ord> Box3<T> {
+    /// The box spanned by two opposite corners, in any order
+    pub fn new(a: Point3<T>, b: Point3<T>) -> Box3<T> {
+        Box3 {
+            min: Point3::new(
+                a.x.clone().min(b.x.clone()),
+                a.y.clone().min(b.y.clone()),
+                a.z.clone().min(b.z.clone()),
+            ),
+            max: Point3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+
+    pub fn contains(&self, p: &Point3<T>) -> bool {
+        self.min.x <= p.x
+            && p.x <= self.max.x
+            && self.min.y <= p.y
+            && p.y <= self.max.y
+            && self.min.z <= p.z
+            && p.z <= self.max.z
+    }
+
+    /// The points the boxes have in common, if any
+    pub fn intersection(&self, other: &Box3<T>) -> Option<Box3<T>> {
+        let min = Point3::new(
+            self.min.x.clone().max(other.min.x.clone()),
+            self.min.y.clone().max(other.min.y.clone()),
+            self.min.z.clone().max(other.min.z.clone()),
+        );
+        let max = Point3::new(
+            self.max.x.clone().min(other.max.x.clone()),
+            self.max.y.clone().min(other.max.y.clone()),
+            self.max.z.clone().min(other.max.z.clone()),
+        );
+        let non_empty = min.x <= max.x && min.y <= max.y && min.z <= max.z;
+        non_empty.then_some(Box3 { min, max })
+    }
+
+    /// Number of points in the box
+    pub fn volume(&self) -> T {
+        let side = |min: &T, max: &T| max.clone() - min.clone() + T::one();
+        side(&self.min.x, &self.max.x)
+            * side(&self.min.y, &self.max.y)
+            * side(&self.min.z, &self.max.z)
+    }
+
+    /// The box moved by `offset`
+    pub fn shifted(&self, offset: &Vec3<T>) -> Box3<T> {
+        Box3 {
+            min: self.min.clone() + offset.clone(),
+            max: self.max.clone() + offset.clone(),
+        }
+    }
+}
+
+impl<T: Clone + Num + Ord + ToPrimitive> Box3<T> {
+    /// The points at height `z` within the x and y range of the box, whether or not the box
+    /// reaches that height
+    pub fn layer(&self, z: T) -> impl Iterator<Item = Point3<T>> + '_ {
+        range_inclusive(self.min.x.clone(), self.max.x.clone()).flat_map(move |x| {
+            let z = z.clone();
+            range_inclusive(self.min.y.clone(), self.max.y.clone())
+                .map(move |y| Point3::new(x.clone(), y, z.clone()))
+        })
+    }
+
+    /// The layers of the box from bottom to top
+    pub fn layers(&self) -> impl Iterator<Item = impl Iterator<Item = Point3<T>> + '_> + '_ {
+        range_inclusive(self.min.z.clone(), self.max.z.clone()).map(|z| self.layer(z))
+    }
+}
+
 pub struct BitSetGrid {
     height: usize,
     width: usize,
@@ -267,6 +976,15 @@ impl BitSetGrid {
         }
     }
 
+    /// The points of `grid` whose value matches `predicate`
+    pub fn from_grid<T>(grid: &Grid<T>, predicate: impl Fn(&T) -> bool) -> BitSetGrid {
+        let mut set = BitSetGrid::new(grid.height(), grid.width());
+        for (p, _) in grid.iter().filter(|(_, value)| predicate(value)) {
+            set.insert(&p);
+        }
+        set
+    }
+
     pub fn from_hashset<H>(col: &HashSet<Point, H>) -> BitSetGrid {
         let bounds = Point::bounds(col);
         let mut grid = BitSetGrid::new(bounds.max_y as usize + 1, bounds.max_x as usize + 1);
@@ -310,6 +1028,19 @@ pub enum Dir {
 }
 
 impl Dir {
+    pub const ALL: [Dir; 4] = [Up, Right, Down, Left];
+
+    /// Reads `U/R/D/L`, `N/E/S/W`, `^>v<` or the digits `0` to `3` for right, down, left and up
+    pub fn from_char(c: char) -> Option<Dir> {
+        match c {
+            'U' | 'N' | '^' | '3' => Some(Up),
+            'R' | 'E' | '>' | '0' => Some(Right),
+            'D' | 'S' | 'v' | '1' => Some(Down),
+            'L' | 'W' | '<' | '2' => Some(Left),
+            _ => None,
+        }
+    }
+
     pub fn cw(&self) -> Dir {
         match self {
             Up => Right,
@@ -338,7 +1069,160 @@ impl Dir {
     }
 }
 
-#[derive(Debug)]
+impl FromStr for Dir {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.chars()
+            .exactly_one()
+            .ok()
+            .and_then(Dir::from_char)
+            .ok_or_else(|| Line(s).error(s, "expected a direction, found"))
+    }
+}
+
+from_field_via_from_str!(Dir, HexDir);
+
+/// The 8 directions to the orthogonal and diagonal neighbours of a [`Point`].
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Copy)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// Clockwise, starting with [`Dir8::Up`]
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    fn index(&self) -> usize {
+        Dir8::ALL.iter().position(|d| d == self).unwrap()
+    }
+
+    /// Turns an eighth clockwise
+    pub fn cw(&self) -> Dir8 {
+        Dir8::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Turns an eighth counterclockwise
+    pub fn ccw(&self) -> Dir8 {
+        Dir8::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn pivot(&self) -> Dir8 {
+        Dir8::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.index() % 2 == 1
+    }
+}
+
+impl From<Dir> for Dir8 {
+    fn from(dir: Dir) -> Self {
+        match dir {
+            Up => Dir8::Up,
+            Right => Dir8::Right,
+            Down => Dir8::Down,
+            Left => Dir8::Left,
+        }
+    }
+}
+
+/// A cell of a grid of pointy-topped hexagons in axial coordinates: `q` grows to the east and `r`
+/// to the south-east. See <https://www.redblobgames.com/grids/hexagons/>.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl Hex {
+    pub fn new(q: i32, r: i32) -> Hex {
+        Hex { q, r }
+    }
+
+    /// The third cube coordinate, `q + r + s == 0`
+    pub fn s(&self) -> i32 {
+        -self.q - self.r
+    }
+
+    pub fn mv(&self, d: HexDir) -> Hex {
+        let (dq, dr) = match d {
+            HexDir::East => (1, 0),
+            HexDir::NorthEast => (1, -1),
+            HexDir::NorthWest => (0, -1),
+            HexDir::West => (-1, 0),
+            HexDir::SouthWest => (-1, 1),
+            HexDir::SouthEast => (0, 1),
+        };
+        Hex::new(self.q + dq, self.r + dr)
+    }
+
+    pub fn neighbours(&self) -> Vec<Hex> {
+        HexDir::ALL.iter().map(|d| self.mv(*d)).collect()
+    }
+
+    /// Number of steps between the cells
+    pub fn distance(&self, other: &Hex) -> i32 {
+        ((self.q - other.q).abs() + (self.r - other.r).abs() + (self.s() - other.s()).abs()) / 2
+    }
+}
+
+/// The 6 directions to the neighbours of a [`Hex`].
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Copy)]
+pub enum HexDir {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+impl HexDir {
+    pub const ALL: [HexDir; 6] = [
+        HexDir::East,
+        HexDir::NorthEast,
+        HexDir::NorthWest,
+        HexDir::West,
+        HexDir::SouthWest,
+        HexDir::SouthEast,
+    ];
+}
+
+impl FromStr for HexDir {
+    type Err = ParseError;
+
+    /// Reads `e`, `ne`, `nw`, `w`, `sw` or `se` in either case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "e" => Ok(HexDir::East),
+            "ne" => Ok(HexDir::NorthEast),
+            "nw" => Ok(HexDir::NorthWest),
+            "w" => Ok(HexDir::West),
+            "sw" => Ok(HexDir::SouthWest),
+            "se" => Ok(HexDir::SouthEast),
+            _ => Err(Line(s).error(s, "expected a hex direction, found")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Bounds {
     pub min_x: i32,
     pub max_x: i32,
@@ -390,9 +1274,379 @@ impl Bounds {
     }
 }
 
+/// A rectangular grid stored row by row, indexed by [`Point`]s with `x` as the column and `y`
+/// as the row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Panics if the rows are not all of the same length, see [`Grid::try_from_rows`] for input
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let height = rows.len();
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "All rows of a grid must have the same length"
+        );
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Like [`Grid::from_rows`], failing on the first row whose length differs from the first one
+    pub fn try_from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, ParseError>
+    where
+        T: Display,
+    {
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        if let Some((i, row)) = rows.iter().find_position(|row| row.len() != width) {
+            return Err(ParseError {
+                section: None,
+                line: Some(i + 1),
+                column: 1,
+                text: row.iter().join(""),
+                message: format!("expected a row of {} cells, found", width),
+            });
+        }
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        Bounds {
+            min_x: 0,
+            max_x: self.width as i32 - 1,
+            min_y: 0,
+            max_y: self.height as i32 - 1,
+        }
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        self.index_of(p).is_some()
+    }
+
+    fn index_of(&self, p: &Point) -> Option<usize> {
+        let in_bounds =
+            p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height;
+        in_bounds.then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    pub fn get(&self, p: &Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: &Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// All points of the grid in row-major order
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The up to four orthogonal neighbours of `p` that lie within the grid
+    pub fn neighbours(&self, p: &Point) -> impl Iterator<Item = (Point, &T)> {
+        p.neighbours()
+            .into_iter()
+            .filter_map(|n| self.get(&n).map(|value| (n, value)))
+    }
+
+    /// The up to eight orthogonal and diagonal neighbours of `p` that lie within the grid
+    pub fn neighbours8(&self, p: &Point) -> impl Iterator<Item = (Point, &T)> {
+        p.neighbours8()
+            .into_iter()
+            .filter_map(|n| self.get(&n).map(|value| (n, value)))
+    }
+
+    /// The first point in row-major order whose value matches `predicate`
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, rows become columns
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point::new(p.y, p.x)].clone()
+        })
+    }
+
+    /// Rotates the grid a quarter turn clockwise
+    pub fn rotate_cw(&self) -> Grid<T> {
+        let h = self.height as i32;
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point::new(p.y, h - 1 - p.x)].clone()
+        })
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        let w = self.width as i32;
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point::new(w - 1 - p.y, p.x)].clone()
+        })
+    }
+
+    /// Mirrors the grid left to right
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let w = self.width as i32;
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Point::new(w - 1 - p.x, p.y)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let h = self.height as i32;
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Point::new(p.x, h - 1 - p.y)].clone()
+        })
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        self.get(&p)
+            .unwrap_or_else(|| panic!("{} is outside of the grid", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut Self::Output {
+        self.get_mut(&p)
+            .unwrap_or_else(|| panic!("{} is outside of the grid", p))
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        Grid::from_rows(rows)
+    }
+}
+
+impl<T: Clone> From<Array2D<T>> for Grid<T> {
+    fn from(array: Array2D<T>) -> Self {
+        Grid::from_rows(array.as_rows())
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Colours for [`Renderer`], only shown when rendering with ANSI escape codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Grey,
+}
+
+impl Colour {
+    fn ansi_code(&self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+            Colour::Grey => 90,
+        }
+    }
+}
+
+/// Draws the points within some [`Bounds`] as text, one line per row, for debugging and for
+/// `--visualize`. Overlays are drawn on top of the cells, later overlays on top of earlier ones.
+pub struct Renderer<'a> {
+    bounds: Bounds,
+    cell: Box<dyn Fn(Point) -> char + 'a>,
+    colour: Box<dyn Fn(char) -> Option<Colour> + 'a>,
+    overlay: FxHashMap<Point, (Option<char>, Colour)>,
+    ansi: bool,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(bounds: Bounds, cell: impl Fn(Point) -> char + 'a) -> Renderer<'a> {
+        Renderer {
+            bounds,
+            cell: Box::new(cell),
+            colour: Box::new(|_| None),
+            overlay: FxHashMap::default(),
+            ansi: false,
+        }
+    }
+
+    pub fn grid<T>(grid: &'a Grid<T>, cell: impl Fn(&T) -> char + 'a) -> Renderer<'a> {
+        Renderer::new(grid.bounds(), move |p| cell(&grid[p]))
+    }
+
+    /// Draws the given points as `#` and everything else within `bounds` as `.`
+    pub fn points<H: BuildHasher>(points: &'a HashSet<Point, H>, bounds: Bounds) -> Renderer<'a> {
+        Renderer::new(bounds, |p| if points.contains(&p) { '#' } else { '.' })
+    }
+
+    /// Colours the cells by the char they are drawn as
+    pub fn colours(mut self, colour: impl Fn(char) -> Option<Colour> + 'a) -> Self {
+        self.colour = Box::new(colour);
+        self
+    }
+
+    /// Colours the given points, keeping their char
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, colour: Colour) -> Self {
+        for p in points {
+            self.overlay.insert(p, (None, colour));
+        }
+        self
+    }
+
+    /// Draws the given points as `symbol` in `colour`
+    pub fn overlay(
+        mut self,
+        points: impl IntoIterator<Item = Point>,
+        symbol: char,
+        colour: Colour,
+    ) -> Self {
+        for p in points {
+            self.overlay.insert(p, (Some(symbol), colour));
+        }
+        self
+    }
+
+    /// Draws each point of `path` as an arrow towards the next one, the last point keeps its char
+    pub fn path(mut self, path: &[Point], colour: Colour) -> Self {
+        for (from, to) in path.iter().tuple_windows() {
+            let arrow = match ((to.x - from.x).signum(), (to.y - from.y).signum()) {
+                (0, -1) => '^',
+                (0, 1) => 'v',
+                (-1, 0) => '<',
+                (1, 0) => '>',
+                _ => '*',
+            };
+            self.overlay.insert(*from, (Some(arrow), colour));
+        }
+        self.highlight(path.last().copied(), colour)
+    }
+
+    /// Whether to colour the output with ANSI escape codes, off by default
+    pub fn ansi(mut self, enabled: bool) -> Self {
+        self.ansi = enabled;
+        self
+    }
+}
+
+impl Display for Renderer<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for y in self.bounds.ys() {
+            for x in self.bounds.xs() {
+                let p = Point { x, y };
+                let (c, colour) = match self.overlay.get(&p) {
+                    Some((symbol, colour)) => {
+                        (symbol.unwrap_or_else(|| (self.cell)(p)), Some(*colour))
+                    }
+                    None => {
+                        let c = (self.cell)(p);
+                        (c, (self.colour)(c))
+                    }
+                };
+                match colour.filter(|_| self.ansi) {
+                    Some(colour) => write!(f, "\x1b[{}m{}\x1b[0m", colour.ansi_code(), c)?,
+                    None => write!(f, "{}", c)?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::util::parse_strings;
+    use crate::util::{
+        parse_lines, AdventHelper, Box3, Colour, Dir, Dir8, Grid, Hex, HexDir, InputSource, Line,
+        ParseError, Point, Point3, Renderer, Vec3,
+    };
+    use itertools::Itertools;
+    use num::BigInt;
     use std::io::Write;
     use tempfile::NamedTempFile;
 
@@ -403,10 +1657,257 @@ mod tests {
             .expect("Failed to write to file");
         let filename = file.path().to_str().expect("Failed to get file path");
 
-        let strings = parse_strings(filename);
+        let strings = AdventHelper::with_source(2023, 1, InputSource::File(filename.to_string()))
+            .load()
+            .unwrap()
+            .lines();
 
         let expected_strings: Vec<String> =
             vec!["This".to_string(), "is a".to_string(), "File!".to_string()];
         assert_eq!(strings, expected_strings);
     }
+
+    #[test]
+    fn resolves_input_files() {
+        let advent = AdventHelper::new(2023, 5);
+
+        assert_eq!(advent.input_file(), "resources/2023/day5.txt");
+        assert_eq!(advent.test().input_file(), "resources/2023/day5.test.txt");
+        assert_eq!(
+            advent.example(2).input_file(),
+            "resources/2023/day5.test2.txt"
+        );
+        assert_eq!(
+            advent.example(2).answers_file(),
+            Some("resources/2023/answers.test2.txt".to_string())
+        );
+        let other = AdventHelper::with_source(2023, 5, InputSource::File("other.txt".to_string()));
+        assert_eq!(other.input_file(), "other.txt");
+        assert_eq!(other.answers_file(), None);
+    }
+
+    #[test]
+    fn reports_where_parsing_failed() {
+        #[derive(Debug, PartialEq)]
+        struct Pair(u32, u32);
+
+        impl std::str::FromStr for Pair {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let line = Line(s);
+                let (a, b) = line.split_once(s, ",")?;
+                Ok(Pair(line.parse(a)?, line.parse(b)?))
+            }
+        }
+
+        assert_eq!(
+            parse_lines::<Pair>("1,2\n3,4"),
+            Ok(vec![Pair(1, 2), Pair(3, 4)])
+        );
+        let error = parse_lines::<Pair>("1,2\n3,x4").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected u32, found `x4`"
+        );
+        let error = parse_lines::<Pair>("1,2\n3").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected `,` in `3`");
+        let error = parse_lines::<i32>("1\n\n3").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: cannot parse integer from empty string ``"
+        );
+    }
+
+    #[test]
+    fn scans_line_patterns() {
+        let line = Line("Game 12: 3 blue, 4 red");
+
+        assert_eq!(
+            scan!(line, "Game {id}: {rounds}", id: u32, rounds: &str),
+            Ok((12, "3 blue, 4 red"))
+        );
+        assert_eq!(
+            scan!(line, &line.0[9..15], "{n} {colour}", n: u32, colour: String),
+            Ok((3, "blue".to_string()))
+        );
+        let error = scan!(line, "Game {id}; {rounds}", id: u32, rounds: &str).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "column 6: expected `; ` in `12: 3 blue, 4 red`"
+        );
+        let error = scan!(line, "Game {id}: {n} blue", id: u32, n: u32).unwrap_err();
+        assert_eq!(error.to_string(), "column 16: unexpected text `, 4 red`");
+    }
+
+    #[test]
+    fn splits_sections() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "seeds: 1 2\n\nsoil map:\n1 2\nx\n\n\nlast:\n").unwrap();
+        let path = file.path().to_str().unwrap().to_string();
+        let advent = AdventHelper::with_source(2023, 5, InputSource::File(path))
+            .load()
+            .unwrap();
+
+        let sections = advent.headed_sections();
+
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].header.as_deref(), Some("seeds"));
+        assert_eq!(sections[0].lines, vec!["1 2"]);
+        assert_eq!(sections[1].lines, vec!["1 2", "x"]);
+        assert_eq!(sections[1].line_number(1), 5);
+        assert!(sections[2].lines.is_empty());
+        let error = sections[1].grid().unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (Some(5), "x"));
+        assert_eq!(advent.sections()[1].lines, vec!["soil map:", "1 2", "x"]);
+        let error = advent
+            .parse_headed_sections(|section| section.parse_lines::<u32>())
+            .unwrap_err();
+        assert_eq!(error.section, Some(1));
+        assert_eq!(error.line, Some(1));
+        assert_eq!(
+            error.to_string(),
+            "section 1, line 1, column 8: invalid digit found in string `1 2`"
+        );
+    }
+
+    #[test]
+    fn transforms_grids() {
+        let grid = Grid::from_rows(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]);
+
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.get(&Point::new(3, 0)), None);
+        assert_eq!(grid.get(&Point::new(0, -1)), None);
+        assert_eq!(grid.find(|c| *c == 'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.neighbours(&Point::new(0, 0)).collect_vec(),
+            vec![(Point::new(0, 1), &'d'), (Point::new(1, 0), &'b')]
+        );
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).row(0), ['A', 'B', 'C']);
+    }
+
+    #[test]
+    fn renders_grids_with_overlays() {
+        let grid = Grid::from_rows(vec![vec!['.', '.', '#'], vec!['.', '.', '.']]);
+        let path = [Point::new(0, 0), Point::new(0, 1), Point::new(1, 1)];
+
+        let renderer = Renderer::grid(&grid, |c| *c)
+            .overlay([Point::new(1, 0)], 'x', Colour::Red)
+            .path(&path, Colour::Yellow);
+        assert_eq!(renderer.to_string(), "vx#\n>..\n");
+
+        let renderer = Renderer::grid(&grid, |c| *c)
+            .colours(|c| (c == '#').then_some(Colour::Grey))
+            .ansi(true);
+        assert_eq!(renderer.to_string(), "..\x1b[90m#\x1b[0m\n...\n");
+    }
+
+    #[test]
+    fn computes_with_wide_points() {
+        let a: Point<i64> = Point {
+            x: 3_000_000_000,
+            y: -2,
+        };
+        let b: Point<i64> = Point { x: -1, y: 5 };
+
+        assert_eq!(
+            a + b,
+            Point {
+                x: 2_999_999_999,
+                y: 3
+            }
+        );
+        assert_eq!(
+            a - b,
+            Point {
+                x: 3_000_000_001,
+                y: -7
+            }
+        );
+        assert_eq!(b * 3, Point { x: -3, y: 15 });
+        assert_eq!(a.manhattan(&b), 3_000_000_008);
+        assert_eq!(a.chebyshev(&b), 3_000_000_001);
+        assert_eq!(b.mv_mulitple(Dir::Up, 10usize), Point { x: -1, y: -5 });
+
+        let big = Point {
+            x: BigInt::from(i128::MAX),
+            y: BigInt::from(0),
+        };
+        assert_eq!(big.right().x, BigInt::from(i128::MAX) + 1);
+        assert_eq!(big.mv_mulitple(Dir::Down, 7usize).y, BigInt::from(7));
+    }
+
+    #[test]
+    fn computes_in_three_dimensions() {
+        let a = Point3::new(1, 2, 3);
+        let b = Vec3::new(4, 5, 6);
+
+        assert_eq!(a + b, Point3::new(5, 7, 9));
+        assert_eq!(b - a, Vec3::new(3, 3, 3));
+        assert_eq!(a * 2, Point3::new(2, 4, 6));
+        assert_eq!(a.dot(&b), 32);
+        assert_eq!(a.cross(&b), Vec3::new(-3, 6, -3));
+        assert_eq!(a.neighbours().len(), 6);
+        assert!(a.neighbours().contains(&Point3::new(1, 2, 2)));
+        assert_eq!(a.neighbours26().len(), 26);
+
+        let cube = Box3::new(Point3::new(2, 0, 0), Point3::new(0, 2, 2));
+        let other = Box3::new(Point3::new(1, 1, 1), Point3::new(5, 5, 5));
+        assert_eq!(cube.volume(), 27);
+        assert!(cube.contains(&Point3::new(2, 2, 2)));
+        assert!(!cube.contains(&Point3::new(3, 2, 2)));
+        assert_eq!(
+            cube.intersection(&other),
+            Some(Box3::new(Point3::new(1, 1, 1), Point3::new(2, 2, 2)))
+        );
+        assert_eq!(cube.intersection(&other.shifted(&Vec3::new(0, 0, 2))), None);
+        assert_eq!(cube.layer(7).count(), 9);
+        assert!(cube.layer(7).all(|p| p.z == 7));
+        assert_eq!(cube.layers().count(), 3);
+    }
+
+    #[test]
+    fn moves_in_eight_directions_and_on_hexagons() {
+        for (input, dir) in [
+            ("U", Dir::Up),
+            ("E", Dir::Right),
+            ("v", Dir::Down),
+            ("2", Dir::Left),
+        ] {
+            assert_eq!(input.parse::<Dir>().unwrap(), dir);
+        }
+        assert_eq!(
+            "X".parse::<Dir>().unwrap_err().message,
+            "expected a direction, found"
+        );
+
+        let p = Point::new(0, 0);
+        assert_eq!(p.mv8(Dir8::DownLeft), Point::new(-1, 1));
+        assert_eq!(p.neighbours8().len(), 8);
+        assert_eq!(Dir8::from(Dir::Left).cw(), Dir8::UpLeft);
+        assert_eq!(Dir8::UpLeft.cw(), Dir8::Up);
+        assert_eq!(Dir8::Up.ccw().pivot(), Dir8::DownRight);
+
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(grid.neighbours8(&Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(&Point::new(1, 1)).count(), 8);
+
+        let path: Vec<HexDir> = ["ne", "NE", "se", "sw"]
+            .map(|d| d.parse().unwrap())
+            .to_vec();
+        let end = path.into_iter().fold(Hex::new(0, 0), |h, d| h.mv(d));
+        assert_eq!(end, Hex::new(1, 0));
+        assert_eq!(end.distance(&Hex::new(0, 0)), 1);
+        assert!(Hex::new(2, 3)
+            .neighbours()
+            .iter()
+            .all(|n| n.distance(&Hex::new(2, 3)) == 1));
+    }
 }
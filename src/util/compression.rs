@@ -0,0 +1,142 @@
+use crate::util::Point;
+use itertools::Itertools;
+use num::Num;
+use std::ops::RangeInclusive;
+
+/// Coordinate compression along one axis: the distinct `values` map to the even indices
+/// `0, 2, 4, ...` in order, and each odd index stands for the whole gap between two neighbouring
+/// values. Sweeps and flood fills then only visit a few cells, which can be weighted by their
+/// [`size`](Compression::size).
+pub struct Compression<T> {
+    values: Vec<T>,
+}
+
+impl<T: Ord + Copy> Compression<T> {
+    pub fn new(values: impl IntoIterator<Item = T>) -> Compression<T> {
+        Compression {
+            values: values.into_iter().sorted().dedup().collect(),
+        }
+    }
+
+    /// Number of compressed indices, including the gaps
+    pub fn len(&self) -> i32 {
+        (2 * self.values.len() as i32 - 1).max(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn compress(&self, value: &T) -> i32 {
+        let ix = self
+            .values
+            .binary_search(value)
+            .unwrap_or_else(|_| panic!("value should be one of the compressed values"));
+        2 * ix as i32
+    }
+
+    /// The original value at an even index
+    pub fn decompress(&self, index: i32) -> T {
+        assert!(index % 2 == 0, "index {index} is a gap between values");
+        self.values[index as usize / 2]
+    }
+}
+
+impl<T: Ord + Copy + Num> Compression<T> {
+    /// The original values that `index` stands for, which is empty for a gap between adjacent values
+    pub fn region(&self, index: i32) -> RangeInclusive<T> {
+        if index % 2 == 0 {
+            let value = self.decompress(index);
+            value..=value
+        } else {
+            self.decompress(index - 1) + T::one()..=self.decompress(index + 1) - T::one()
+        }
+    }
+
+    /// Number of original values that `index` stands for
+    pub fn size(&self, index: i32) -> T {
+        if index % 2 == 0 {
+            T::one()
+        } else {
+            self.decompress(index + 1) - self.decompress(index - 1) - T::one()
+        }
+    }
+}
+
+/// [`Compression`] of both axes of a plane.
+pub struct GridCompression<T> {
+    pub x: Compression<T>,
+    pub y: Compression<T>,
+}
+
+impl<T: Ord + Copy> GridCompression<T> {
+    pub fn new<'a>(points: impl IntoIterator<Item = &'a Point<T>> + Clone) -> GridCompression<T>
+    where
+        T: 'a,
+    {
+        GridCompression {
+            x: Compression::new(points.clone().into_iter().map(|p| p.x)),
+            y: Compression::new(points.into_iter().map(|p| p.y)),
+        }
+    }
+
+    pub fn compress(&self, p: &Point<T>) -> Point {
+        Point::new(self.x.compress(&p.x), self.y.compress(&p.y))
+    }
+
+    pub fn decompress(&self, p: &Point) -> Point<T> {
+        Point {
+            x: self.x.decompress(p.x),
+            y: self.y.decompress(p.y),
+        }
+    }
+}
+
+impl<T: Ord + Copy + Num> GridCompression<T> {
+    /// The original points that the compressed point stands for, as ranges of x and y
+    pub fn region(&self, p: &Point) -> (RangeInclusive<T>, RangeInclusive<T>) {
+        (self.x.region(p.x), self.y.region(p.y))
+    }
+
+    /// Number of original points that the compressed point stands for
+    pub fn area(&self, p: &Point) -> T {
+        self.x.size(p.x) * self.y.size(p.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::compression::{Compression, GridCompression};
+    use crate::util::Point;
+
+    #[test]
+    fn compresses_coordinates() {
+        let axis = Compression::new([10, 3, 4, 10]);
+        assert_eq!(axis.len(), 5);
+        assert_eq!(axis.compress(&10), 4);
+        assert_eq!(axis.decompress(2), 4);
+        assert_eq!(axis.size(1), 0);
+        assert_eq!(axis.size(3), 5);
+        assert_eq!(axis.region(3), 5..=9);
+        assert!(axis.region(1).is_empty());
+
+        let corners = [
+            Point { x: 0i64, y: 0 },
+            Point {
+                x: 5_000_000_000,
+                y: 7,
+            },
+        ];
+        let grid = GridCompression::new(&corners);
+        assert_eq!(grid.compress(&corners[1]), Point::new(2, 2));
+        assert_eq!(
+            grid.decompress(&Point::new(2, 0)),
+            Point {
+                x: 5_000_000_000,
+                y: 0
+            }
+        );
+        assert_eq!(grid.area(&Point::new(1, 1)), 4_999_999_999 * 6);
+        assert_eq!(grid.region(&Point::new(0, 1)), (0..=0, 1..=6));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::util::Point;
+use num::{Integer, Signed};
+
+/// Twice the area enclosed by `corners`, using the shoelace formula. The polygon is closed
+/// implicitly, so the first corner may but does not need to be repeated at the end.
+pub fn double_area<T: Copy + Integer + Signed>(corners: &[Point<T>]) -> T {
+    edges(corners)
+        .fold(T::zero(), |sum, (a, b)| sum + a.x * b.y - b.x * a.y)
+        .abs()
+}
+
+/// Number of lattice points on the edges between `corners`
+pub fn boundary_points<T: Copy + Integer + Signed>(corners: &[Point<T>]) -> T {
+    edges(corners).fold(T::zero(), |sum, (a, b)| {
+        sum + (b.x - a.x).abs().gcd(&(b.y - a.y).abs())
+    })
+}
+
+/// Number of lattice points strictly inside the polygon, using Pick's theorem
+pub fn interior_points<T: Copy + Integer + Signed>(corners: &[Point<T>]) -> T {
+    let two = T::one() + T::one();
+    (double_area(corners) - boundary_points(corners) + two) / two
+}
+
+/// Number of lattice points inside or on the edges of the polygon. For a polygon traced through
+/// the centres of grid cells, this is the number of cells it covers.
+pub fn lattice_points<T: Copy + Integer + Signed>(corners: &[Point<T>]) -> T {
+    interior_points(corners) + boundary_points(corners)
+}
+
+fn edges<T: Copy>(corners: &[Point<T>]) -> impl Iterator<Item = (Point<T>, Point<T>)> + '_ {
+    corners
+        .iter()
+        .zip(corners.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::geometry::{boundary_points, double_area, interior_points, lattice_points};
+    use crate::util::Point;
+
+    #[test]
+    fn counts_lattice_points_of_polygons() {
+        let square = [(0, 0), (4, 0), (4, 4), (0, 4)].map(|(x, y)| Point::new(x, y));
+        assert_eq!(double_area(&square), 32);
+        assert_eq!(boundary_points(&square), 16);
+        assert_eq!(interior_points(&square), 9);
+        assert_eq!(lattice_points(&square), 25);
+
+        let triangle: [Point<i64>; 4] =
+            [(0, 0), (4, 2), (0, 6), (0, 0)].map(|(x, y)| Point { x, y });
+        assert_eq!(double_area(&triangle), 24);
+        assert_eq!(boundary_points(&triangle), 12);
+        assert_eq!(interior_points(&triangle), 7);
+
+        let huge = [(0, 0), (3_000_000_000i64, 0), (0, 3_000_000_000)].map(|(x, y)| Point { x, y });
+        assert_eq!(double_area(&huge), 9_000_000_000_000_000_000);
+    }
+}
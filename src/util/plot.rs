@@ -0,0 +1,238 @@
+use crate::util::{Bounds, Colour, Grid, Point};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::path::Path;
+
+/// Width or height of the longer side of an exported picture in pixels
+const MAX_SIZE: f64 = 800.0;
+const FRAME_DELAY_MS: u32 = 100;
+
+/// One picture of the puzzle state: coloured cells with polygons and paths drawn on top,
+/// see [`save`] and [`save_animation`].
+pub struct Frame {
+    bounds: Bounds,
+    cells: Vec<(Point, Colour)>,
+    polygons: Vec<(Vec<Point>, Colour)>,
+    paths: Vec<(Vec<Point>, Colour)>,
+}
+
+impl Frame {
+    pub fn new(bounds: Bounds) -> Frame {
+        Frame {
+            bounds,
+            cells: vec![],
+            polygons: vec![],
+            paths: vec![],
+        }
+    }
+
+    /// Fills the cells of `grid` that `colour` returns a colour for
+    pub fn grid<T>(grid: &Grid<T>, colour: impl Fn(&T) -> Option<Colour>) -> Frame {
+        let cells = grid
+            .iter()
+            .filter_map(|(p, value)| colour(value).map(|c| (p, c)))
+            .collect();
+        Frame {
+            cells,
+            ..Frame::new(grid.bounds())
+        }
+    }
+
+    pub fn points(mut self, points: impl IntoIterator<Item = Point>, colour: Colour) -> Self {
+        self.cells.extend(points.into_iter().map(|p| (p, colour)));
+        self
+    }
+
+    /// A line through the centres of the cells of `path`
+    pub fn path(mut self, path: &[Point], colour: Colour) -> Self {
+        self.paths.push((path.to_vec(), colour));
+        self
+    }
+
+    /// A filled polygon with the centres of the cells of `corners` as its corners
+    pub fn polygon(mut self, corners: &[Point], colour: Colour) -> Self {
+        self.polygons.push((corners.to_vec(), colour));
+        self
+    }
+}
+
+/// Saves a single frame as `.png` or `.svg`, depending on the extension of `path`.
+pub fn save(frame: &Frame, path: &Path) -> Result<(), String> {
+    let layout = Layout::new(&frame.bounds);
+    match extension(path).as_deref() {
+        Some("png") => {
+            let area = BitMapBackend::new(path, layout.size).into_drawing_area();
+            draw(&area, frame, &layout)
+        }
+        Some("svg") => {
+            let area = SVGBackend::new(path, layout.size).into_drawing_area();
+            draw(&area, frame, &layout)
+        }
+        _ => Err(format!(
+            "{} is neither a .png nor a .svg file",
+            path.display()
+        )),
+    }
+}
+
+/// Saves `frames` as an animated `.gif`, or as `frame0001.png`, `frame0002.png`, ... when `path`
+/// has no extension, creating the directory if needed.
+pub fn save_animation(frames: &[Frame], path: &Path) -> Result<(), String> {
+    let bounds = frames
+        .iter()
+        .map(|frame| frame.bounds)
+        .reduce(|a, b| Bounds {
+            min_x: a.min_x.min(b.min_x),
+            max_x: a.max_x.max(b.max_x),
+            min_y: a.min_y.min(b.min_y),
+            max_y: a.max_y.max(b.max_y),
+        })
+        .ok_or("There are no frames to save")?;
+    let layout = Layout::new(&bounds);
+    match extension(path).as_deref() {
+        Some("gif") => {
+            let area = BitMapBackend::gif(path, layout.size, FRAME_DELAY_MS)
+                .map_err(|e| e.to_string())?
+                .into_drawing_area();
+            frames
+                .iter()
+                .try_for_each(|frame| draw(&area, frame, &layout))
+        }
+        None => {
+            std::fs::create_dir_all(path).map_err(|e| e.to_string())?;
+            frames.iter().enumerate().try_for_each(|(i, frame)| {
+                let file = path.join(format!("frame{:04}.png", i + 1));
+                let area = BitMapBackend::new(&file, layout.size).into_drawing_area();
+                draw(&area, frame, &layout)
+            })
+        }
+        _ => Err(format!(
+            "An animation can only be saved as a .gif file or to a directory, not to {}",
+            path.display()
+        )),
+    }
+}
+
+fn extension(path: &Path) -> Option<String> {
+    path.extension()
+        .map(|e| e.to_string_lossy().to_ascii_lowercase())
+}
+
+/// Maps the cells within some bounds to pixels, scaled so that the longer side fits [`MAX_SIZE`]
+struct Layout {
+    min_x: i32,
+    min_y: i32,
+    scale: f64,
+    size: (u32, u32),
+}
+
+impl Layout {
+    fn new(bounds: &Bounds) -> Layout {
+        let width = (bounds.max_x - bounds.min_x + 1) as f64;
+        let height = (bounds.max_y - bounds.min_y + 1) as f64;
+        let scale = MAX_SIZE / width.max(height);
+        Layout {
+            min_x: bounds.min_x,
+            min_y: bounds.min_y,
+            scale,
+            size: (
+                ((width * scale).ceil() as u32).max(1),
+                ((height * scale).ceil() as u32).max(1),
+            ),
+        }
+    }
+
+    fn corner(&self, x: f64, y: f64) -> (i32, i32) {
+        (
+            ((x - self.min_x as f64) * self.scale) as i32,
+            ((y - self.min_y as f64) * self.scale) as i32,
+        )
+    }
+
+    fn centre(&self, p: &Point) -> (i32, i32) {
+        self.corner(p.x as f64 + 0.5, p.y as f64 + 0.5)
+    }
+
+    fn cell(&self, p: &Point) -> [(i32, i32); 2] {
+        let (x0, y0) = self.corner(p.x as f64, p.y as f64);
+        let (x1, y1) = self.corner(p.x as f64 + 1.0, p.y as f64 + 1.0);
+        [(x0, y0), (x1.max(x0 + 1), y1.max(y0 + 1))]
+    }
+}
+
+fn draw<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    frame: &Frame,
+    layout: &Layout,
+) -> Result<(), String> {
+    let error = |e: DrawingAreaErrorKind<DB::ErrorType>| e.to_string();
+    area.fill(&BLACK).map_err(error)?;
+    for (p, colour) in &frame.cells {
+        area.draw(&Rectangle::new(layout.cell(p), rgb(colour).filled()))
+            .map_err(error)?;
+    }
+    let line_width = (layout.scale / 4.0).ceil() as u32;
+    for (corners, colour) in &frame.polygons {
+        let corners = corners.iter().map(|p| layout.centre(p)).collect::<Vec<_>>();
+        area.draw(&Polygon::new(
+            corners.clone(),
+            rgb(colour).mix(0.4).filled(),
+        ))
+        .map_err(error)?;
+        let outline = corners.iter().chain(corners.first()).copied();
+        area.draw(&PathElement::new(
+            outline.collect::<Vec<_>>(),
+            rgb(colour).stroke_width(line_width),
+        ))
+        .map_err(error)?;
+    }
+    for (path, colour) in &frame.paths {
+        let points = path.iter().map(|p| layout.centre(p)).collect::<Vec<_>>();
+        area.draw(&PathElement::new(
+            points,
+            rgb(colour).stroke_width(line_width),
+        ))
+        .map_err(error)?;
+    }
+    area.present().map_err(error)
+}
+
+fn rgb(colour: &Colour) -> RGBColor {
+    match colour {
+        Colour::Red => RGBColor(220, 50, 47),
+        Colour::Green => RGBColor(133, 153, 0),
+        Colour::Yellow => RGBColor(240, 200, 40),
+        Colour::Blue => RGBColor(38, 139, 210),
+        Colour::Magenta => RGBColor(211, 54, 130),
+        Colour::Cyan => RGBColor(42, 161, 152),
+        Colour::Grey => RGBColor(110, 110, 110),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::plot::{save, save_animation, Frame};
+    use crate::util::{Colour, Grid, Point};
+
+    #[test]
+    fn saves_pictures_and_animations() {
+        let dir = tempfile::tempdir().unwrap();
+        let grid = Grid::from_rows(vec![vec!['.', '#'], vec!['#', '.']]);
+        let frame = || {
+            Frame::grid(&grid, |c| (*c == '#').then_some(Colour::Grey))
+                .path(&[Point::new(0, 0), Point::new(1, 1)], Colour::Red)
+        };
+
+        save(&frame(), &dir.path().join("grid.png")).unwrap();
+        save(&frame(), &dir.path().join("grid.svg")).unwrap();
+        save_animation(&[frame(), frame()], &dir.path().join("grid.gif")).unwrap();
+        save_animation(&[frame(), frame()], &dir.path().join("frames")).unwrap();
+
+        assert!(dir.path().join("grid.png").exists());
+        assert!(dir.path().join("grid.gif").exists());
+        assert!(dir.path().join("frames/frame0002.png").exists());
+        let svg = std::fs::read_to_string(dir.path().join("grid.svg")).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(save(&frame(), &dir.path().join("grid.txt")).is_err());
+    }
+}
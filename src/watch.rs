@@ -0,0 +1,238 @@
+use crate::answers::{Answers, Verdict};
+use crate::runner::{run_day, DayRun};
+use crate::util::InputSource;
+use rustc_hash::FxHashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use std::{env, fs, panic, process, thread};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Detects changes to a set of files by polling their modification time and size.
+/// The files are listed again on every poll, so files that are created later are noticed too.
+pub struct Watcher<F: Fn() -> Vec<PathBuf>> {
+    files: F,
+    seen: FxHashMap<PathBuf, Option<(SystemTime, u64)>>,
+}
+
+impl<F: Fn() -> Vec<PathBuf>> Watcher<F> {
+    pub fn new(files: F) -> Self {
+        let mut watcher = Self {
+            files,
+            seen: FxHashMap::default(),
+        };
+        watcher.changes();
+        watcher
+    }
+
+    /// The files that changed since the last call, including files that were created or removed
+    pub fn changes(&mut self) -> Vec<PathBuf> {
+        let current: FxHashMap<PathBuf, Option<(SystemTime, u64)>> = (self.files)()
+            .into_iter()
+            .map(|file| {
+                let metadata = fs::metadata(&file).ok();
+                let stamp = metadata.and_then(|m| Some((m.modified().ok()?, m.len())));
+                (file, stamp)
+            })
+            .collect();
+        let mut changed: Vec<PathBuf> = current
+            .iter()
+            .filter(|(file, stamp)| self.seen.get(*file) != Some(stamp))
+            .map(|(file, _)| file.clone())
+            .chain(
+                self.seen
+                    .keys()
+                    .filter(|file| !current.contains_key(*file))
+                    .cloned(),
+            )
+            .collect();
+        changed.sort();
+        self.seen = current;
+        changed
+    }
+
+    /// Blocks until at least one of the files changes
+    pub fn wait(&mut self) -> Vec<PathBuf> {
+        loop {
+            let changed = self.changes();
+            if !changed.is_empty() {
+                return changed;
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+/// The input files of `day` in `dir`, i.e. `day<day>.txt` and all its examples.
+pub fn input_files(dir: &str, day: u32) -> Vec<PathBuf> {
+    let prefix = format!("day{}.", day);
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let name = path.file_name().unwrap().to_string_lossy();
+            name.starts_with(&prefix) && name.ends_with(".txt")
+        })
+        .collect()
+}
+
+/// Answers of the previous run of [`watch`], passed on when it restarts itself after a rebuild
+const WATCH_ANSWERS: &str = "AOC_WATCH_ANSWERS";
+
+/// Solves `days` again whenever their input files change. When their source files in this
+/// checkout change, rebuilds it and restarts the current process with the same arguments.
+pub fn watch(year: u32, days: &[u32], source: &InputSource, parts: &[u32]) -> ! {
+    let checkout = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let sources = days
+        .iter()
+        .map(|day| checkout.join(format!("src/year{}/day{}.rs", year, day)))
+        .filter(|file| file.exists())
+        .collect::<Vec<_>>();
+    let watched = || {
+        let mut files: Vec<PathBuf> = match source {
+            InputSource::File(path) => vec![PathBuf::from(path)],
+            _ => days
+                .iter()
+                .flat_map(|&day| input_files(&format!("resources/{}", year), day))
+                .collect(),
+        };
+        files.extend(sources.iter().cloned());
+        files
+    };
+    let mut watcher = Watcher::new(watched);
+    let mut previous = env::var(WATCH_ANSWERS)
+        .ok()
+        .and_then(|answers| Answers::parse(&answers).ok())
+        .unwrap_or_default();
+    loop {
+        for &day in days {
+            let Ok(day_run) = panic::catch_unwind(|| run_day(year, day, source, parts)) else {
+                println!("Day {}: panicked", day);
+                continue;
+            };
+            match day_run {
+                Ok(day_run) => changes(&mut previous, &day_run)
+                    .iter()
+                    .for_each(|line| println!("{}", line)),
+                Err(e) => println!("{}", e),
+            }
+        }
+        println!("Watching for changes...");
+        let changed = watcher.wait();
+        println!();
+        if changed.iter().any(|file| sources.contains(file)) {
+            rebuild_and_restart(checkout, &previous);
+        }
+    }
+}
+
+/// One line per answer of `day_run`, saying whether it changed since `previous`, which is
+/// updated to the new answers
+fn changes(previous: &mut Answers, day_run: &DayRun) -> Vec<String> {
+    let mut lines = vec![];
+    for (part, answer) in &day_run.answers {
+        let change = match previous.verify(day_run.day, *part, answer) {
+            Verdict::Pass => " (unchanged)".to_string(),
+            Verdict::Fail { expected } => format!(" (was {})", expected),
+            Verdict::Missing => String::new(),
+        };
+        lines.push(format!(
+            "Day {}, Part {}: {}{}",
+            day_run.day, part, answer, change
+        ));
+        previous.insert(day_run.day, *part, answer);
+    }
+    lines
+}
+
+/// Rebuilds the checkout and replaces the current process by the new binary. Returns when the
+/// build fails, so that watching can continue.
+fn rebuild_and_restart(checkout: &Path, previous: &Answers) {
+    // Linux no longer resolves the path of the executable once the build replaced it
+    let executable = env::current_exe().expect("Unknown executable");
+    let mut build = process::Command::new(option_env!("CARGO").unwrap_or("cargo"));
+    build.arg("build").arg("--manifest-path");
+    build.arg(checkout.join("Cargo.toml"));
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+    if !build.status().is_ok_and(|status| status.success()) {
+        println!("Build failed");
+        return;
+    }
+    let mut restart = process::Command::new(executable);
+    restart.args(env::args_os().skip(1));
+    restart.env(WATCH_ANSWERS, previous.to_string());
+    restart_with(restart)
+}
+
+#[cfg(unix)]
+fn restart_with(mut command: process::Command) {
+    use std::os::unix::process::CommandExt;
+    let error = command.exec();
+    println!("Could not restart: {}", error);
+}
+
+#[cfg(not(unix))]
+fn restart_with(mut command: process::Command) {
+    match command.status() {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(error) => println!("Could not restart: {}", error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::Answers;
+    use crate::runner::{DayRun, Timings};
+    use crate::solution::Answer;
+    use crate::watch::{changes, input_files, Watcher};
+    use std::fs;
+
+    #[test]
+    fn notices_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = |name: &str| dir.path().join(name);
+        fs::write(file("day3.txt"), "1").unwrap();
+        fs::write(file("day31.txt"), "1").unwrap();
+        let dir_name = dir.path().to_str().unwrap().to_string();
+        let mut watcher = Watcher::new(|| input_files(&dir_name, 3));
+
+        assert!(watcher.changes().is_empty());
+
+        fs::write(file("day3.txt"), "12").unwrap();
+        fs::write(file("day3.test.txt"), "1").unwrap();
+        fs::write(file("day31.txt"), "12").unwrap();
+
+        assert_eq!(
+            watcher.changes(),
+            vec![file("day3.test.txt"), file("day3.txt")]
+        );
+        assert!(watcher.changes().is_empty());
+    }
+
+    #[test]
+    fn reports_changed_answers() {
+        let day_run = |answer: i32| DayRun {
+            year: 2023,
+            day: 3,
+            input_file: "resources/2023/day3.txt".to_string(),
+            answers: vec![(1, Answer::from(answer))],
+            timings: Timings::default(),
+        };
+        let mut previous = Answers::default();
+
+        assert_eq!(changes(&mut previous, &day_run(4)), ["Day 3, Part 1: 4"]);
+        assert_eq!(
+            changes(&mut previous, &day_run(4)),
+            ["Day 3, Part 1: 4 (unchanged)"]
+        );
+        assert_eq!(
+            changes(&mut previous, &day_run(5)),
+            ["Day 3, Part 1: 5 (was 4)"]
+        );
+    }
+}
@@ -1,23 +1,29 @@
-use crate::util;
-use crate::util::day;
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, ParseError};
 use itertools::Itertools;
 
-pub fn main() {
-    let day = day(file!());
-    let file_name = &format!("resources/day{}.txt", day);
-    let input = util::parse_strings(file_name);
-
-    println!(
-        "Day 1, Part 1: Calibration value {:?}",
-        calibration_value(input.clone(), false)
-    );
-    println!(
-        "Day 1, Part 2: Calibration value {:?}",
-        calibration_value(input.clone(), true)
-    );
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+
+    const PART1: &'static str = "Calibration value {}";
+    const PART2: &'static str = "Calibration value {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.parse_from_strings()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        calibration_value(input, false).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        calibration_value(input, true).into()
+    }
 }
 
-fn calibration_value(calibration_strings: Vec<String>, allow_words: bool) -> i32 {
+fn calibration_value(calibration_strings: &[String], allow_words: bool) -> i32 {
     calibration_strings
         .iter()
         .map(|s| find_digits(s, allow_words))
@@ -1,19 +1,62 @@
-use crate::util::{AdventHelper, Point};
+use crate::solution::{Answer, Solution};
+use crate::util::geometry::interior_points;
+use crate::util::{AdventHelper, Colour, Grid, ParseError, Point, Renderer};
 use itertools::Itertools;
 
 use pathfinding::prelude::dfs_reach;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
-pub fn main() {
-    let advent = AdventHelper::from_file_name(file!());
-    let lines: Vec<String> = advent.parse_from_strings();
-    let grid = to_grid(&lines);
+pub struct Day10;
 
-    advent.part1("farthest distance: {}", farthest(&grid));
-    advent.part2("number of inside points: {}", count_inside_points(&grid));
+impl Solution for Day10 {
+    type Input = Grid<char>;
+
+    const PART1: &'static str = "farthest distance: {}";
+    const PART2: &'static str = "number of inside points: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.grid()
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Answer {
+        farthest(grid).into()
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Answer {
+        inside_points_by_pick(grid).into()
+    }
+
+    fn visualize<'a>(&self, grid: &'a Self::Input) -> Option<Renderer<'a>> {
+        let start = grid.find(|c| *c == 'S')?;
+        let renderer = Renderer::grid(grid, |c| match c {
+            '|' => '│',
+            '-' => '─',
+            'L' => '└',
+            'J' => '┘',
+            '7' => '┐',
+            'F' => '┌',
+            c => *c,
+        })
+        .highlight(find_loop(grid), Colour::Yellow)
+        .highlight(inside_points(grid), Colour::Green)
+        .highlight([start], Colour::Red);
+        Some(renderer)
+    }
 }
 
-fn count_inside_points(grid: &HashMap<Point, char>) -> usize {
+/// Counts the tiles enclosed by the loop from the loop alone, using Pick's theorem
+pub fn inside_points_by_pick(grid: &Grid<char>) -> usize {
+    interior_points(&find_loop(grid)) as usize
+}
+
+/// Counts the tiles enclosed by the loop by flood filling the outside, see [`inside_points`]
+pub fn inside_points_by_flood_fill(grid: &Grid<char>) -> usize {
+    inside_points(grid).len()
+}
+
+/// The tiles enclosed by the loop, found by flood filling the grid at triple resolution so that
+/// the outside can squeeze between pipes
+fn inside_points(grid: &Grid<char>) -> HashSet<Point> {
     let zoomed_in_loop = zoomed_in_loop(grid);
     let bounds = Point::bounds(&zoomed_in_loop).expand(1);
     let outside_point = Point {
@@ -27,10 +70,9 @@ fn count_inside_points(grid: &HashMap<Point, char>) -> usize {
             .filter(|n| !zoomed_in_loop.contains(n) && bounds.contains(n))
             .collect_vec()
     })
-    .into_iter()
     .collect();
 
-    let mut inside_count = 0;
+    let mut inside = HashSet::new();
     for y in bounds.min_y..=bounds.max_y {
         for x in bounds.min_x..=bounds.max_x {
             let is_outside = outside.contains(&Point { x, y });
@@ -38,14 +80,17 @@ fn count_inside_points(grid: &HashMap<Point, char>) -> usize {
             let is_original_grid_point = x % 3 == 1 && y % 3 == 1;
             let is_inside = !is_outside && !is_loop && is_original_grid_point;
             if is_inside {
-                inside_count += 1;
+                inside.insert(Point {
+                    x: (x - 1) / 3,
+                    y: (y - 1) / 3,
+                });
             }
         }
     }
-    inside_count
+    inside
 }
 
-fn zoomed_in_loop(grid: &HashMap<Point, char>) -> HashSet<Point> {
+fn zoomed_in_loop(grid: &Grid<char>) -> HashSet<Point> {
     let das_loop: HashSet<Point> = HashSet::from_iter(find_loop(grid));
     let mut zoomed_in = HashSet::new();
     for p in das_loop {
@@ -82,19 +127,19 @@ fn zoomed_in_loop(grid: &HashMap<Point, char>) -> HashSet<Point> {
     zoomed_in
 }
 
-fn farthest(grid: &HashMap<Point, char>) -> usize {
+fn farthest(grid: &Grid<char>) -> usize {
     find_loop(grid).len() / 2
 }
 
-fn find_loop(grid: &HashMap<Point, char>) -> Vec<Point> {
-    let (start, _) = grid.iter().find(|(_, c)| **c == 'S').unwrap();
-    let mut prev = start.clone();
+fn find_loop(grid: &Grid<char>) -> Vec<Point> {
+    let start = grid.find(|c| *c == 'S').unwrap();
+    let mut prev = start;
     let mut current = Point {
         x: start.x,
         y: start.y + 1,
     };
-    let mut points = vec![start.clone()];
-    while current != *start {
+    let mut points = vec![start];
+    while current != start {
         points.push(current);
         let (a, b) = match grid.get(&current).unwrap() {
             '|' => (
@@ -166,18 +211,32 @@ fn find_loop(grid: &HashMap<Point, char>) -> Vec<Point> {
     points
 }
 
-fn to_grid(lines: &Vec<String>) -> HashMap<Point, char> {
-    let mut m = HashMap::new();
-    for (y, line) in lines.iter().enumerate() {
-        for (x, char) in line.chars().enumerate() {
-            m.insert(
-                Point {
-                    x: x as i32,
-                    y: y as i32,
-                },
-                char,
-            );
+#[cfg(test)]
+mod tests {
+    use crate::util::Grid;
+    use crate::year2023::day10::{inside_points_by_flood_fill, inside_points_by_pick};
+
+    #[test]
+    fn counts_inside_points_both_ways() {
+        let examples = [
+            (
+                "...........\n.S-------7.\n.|F-----7|.\n.||.....||.\n.||.....||.\n\
+                 .|L-7.F-J|.\n.|..|.|..|.\n.L--J.L--J.\n...........",
+                4,
+            ),
+            (
+                ".F----7F7F7F7F-7....\n.|F--7||||||||FJ....\n.||.FJ||||||||L7....\n\
+                 FJL7L7LJLJ||LJ.L-7..\nL--J.L7...LJS7F-7L7.\n....F-J..F7FJ|L7L7L7\n\
+                 ....L7.F7||L7|.L7L7|\n.....|FJLJ|FJ|F7|.LJ\n....FJL-7.||.||||...\n\
+                 ....L---J.LJ.LJLJ...",
+                8,
+            ),
+        ];
+        for (example, inside) in examples {
+            let grid = Grid::from_rows(example.lines().map(|l| l.chars().collect()).collect());
+
+            assert_eq!(inside_points_by_pick(&grid), inside);
+            assert_eq!(inside_points_by_flood_fill(&grid), inside);
         }
     }
-    m
 }
@@ -1,42 +1,55 @@
-use crate::util::{AdventHelper, Point};
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, ParseError, Point};
 use itertools::Itertools;
 use std::cmp::max;
 
-use num::abs;
-
 use std::collections::HashSet;
 
-pub fn main() {
-    let advent = AdventHelper::from_file_name(file!());
-    let lines: Vec<String> = advent.parse_from_strings();
-    let grid = to_grid(&lines);
+pub struct Day11;
 
-    advent.part1("sum of distances: {}", sum_of_distances(&grid, 2));
-    advent.part2("sum of distances: {}", sum_of_distances(&grid, 1_000_000));
-}
+impl Solution for Day11 {
+    type Input = HashSet<Point>;
 
-fn sum_of_distances(galaxies: &HashSet<Point>, factor: i32) -> i64 {
-    let galaxies = expand(galaxies, factor);
-    let mut sum = 0;
-    for a in &galaxies {
-        for b in &galaxies {
-            sum += (abs(a.x - b.x) + abs(a.y - b.y)) as i64;
-        }
+    const PART1: &'static str = "sum of distances: {}";
+    const PART2: &'static str = "sum of distances: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        let lines: Vec<String> = advent.parse_from_strings()?;
+        Ok(to_grid(&lines))
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Answer {
+        sum_of_distances(grid, 2).into()
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Answer {
+        sum_of_distances(grid, 1_000_000).into()
     }
-    sum / 2
 }
 
-fn expand(galaxies: &HashSet<Point>, factor: i32) -> HashSet<Point> {
+fn sum_of_distances(galaxies: &HashSet<Point>, factor: i64) -> i64 {
+    expand(galaxies, factor)
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| a.manhattan(b))
+        .sum()
+}
+
+fn expand(galaxies: &HashSet<Point>, factor: i64) -> HashSet<Point<i64>> {
+    let galaxies = galaxies.iter().map(|g| Point {
+        x: g.x as i64,
+        y: g.y as i64,
+    });
     let mut x_empty_space = 0;
     let mut x_expanded_galaxies = HashSet::new();
     let mut x_previous_galaxy = Point { x: 0, y: 0 };
-    for galaxy in galaxies.iter().sorted_by_key(|g| g.x) {
+    for galaxy in galaxies.sorted_by_key(|g| g.x) {
         x_empty_space += max(0, (galaxy.x - x_previous_galaxy.x - 1) * (factor - 1));
         x_expanded_galaxies.insert(Point {
             x: galaxy.x + x_empty_space,
             y: galaxy.y,
         });
-        x_previous_galaxy = galaxy.clone()
+        x_previous_galaxy = galaxy
     }
     let mut y_empty_space = 0;
     let mut y_expanded_galaxies = HashSet::new();
@@ -47,7 +60,7 @@ fn expand(galaxies: &HashSet<Point>, factor: i32) -> HashSet<Point> {
             x: galaxy.x,
             y: galaxy.y + y_empty_space,
         });
-        y_previous_galaxy = galaxy.clone()
+        y_previous_galaxy = *galaxy
     }
     y_expanded_galaxies
 }
@@ -56,7 +69,7 @@ fn expand(galaxies: &HashSet<Point>, factor: i32) -> HashSet<Point> {
 //
 // }
 
-fn to_grid(lines: &Vec<String>) -> HashSet<Point> {
+fn to_grid(lines: &[String]) -> HashSet<Point> {
     let mut m = HashSet::new();
     for (y, line) in lines.iter().enumerate() {
         for (x, char) in line.chars().enumerate() {
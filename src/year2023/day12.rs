@@ -1,21 +1,29 @@
-use crate::util::AdventHelper;
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, Line, ParseError};
 use itertools::{repeat_n, Itertools};
 
 use rustc_hash::FxHashMap;
 use std::str::FromStr;
 
-pub fn main() {
-    let advent = AdventHelper::from_file_name(file!());
-    let springs: Vec<Spring> = advent.parse_from_strings();
-
-    advent.part1(
-        "number of arrangements: {}",
-        count_total_combinations(&springs, 1),
-    );
-    advent.part2(
-        "number of arrangements: {}",
-        count_total_combinations(&springs, 5),
-    );
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Spring>;
+
+    const PART1: &'static str = "number of arrangements: {}";
+    const PART2: &'static str = "number of arrangements: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.parse_from_strings()
+    }
+
+    fn part1(&self, springs: &Self::Input) -> Answer {
+        count_total_combinations(springs, 1).into()
+    }
+
+    fn part2(&self, springs: &Self::Input) -> Answer {
+        count_total_combinations(springs, 5).into()
+    }
 }
 
 fn count_total_combinations(springs: &[Spring], multiplicity: usize) -> u64 {
@@ -72,20 +80,18 @@ fn count_combinations(spring: &Spring) -> u64 {
 }
 
 #[derive(Debug)]
-struct Spring {
+pub struct Spring {
     row: String,
     constraint: Vec<i32>,
 }
 
 impl FromStr for Spring {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (row, raw_constraint) = s.split(" ").collect_tuple().unwrap();
-        let constraint = raw_constraint
-            .split(",")
-            .map(|x| x.parse().unwrap())
-            .collect_vec();
+        let line = Line(s);
+        let (row, raw_constraint) = line.split_once(s, " ")?;
+        let constraint = line.parse_list(raw_constraint, ",")?;
         Ok(Spring {
             row: row.to_string(),
             constraint,
@@ -0,0 +1,53 @@
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, Grid, ParseError};
+use itertools::Itertools;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Grid<char>>;
+
+    const PART1: &'static str = "summarize grids: {}";
+    const PART2: &'static str = "summarize grids: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.parse_sections(|section| section.grid())
+    }
+
+    fn part1(&self, grids: &Self::Input) -> Answer {
+        summarize_grids(grids, 0).into()
+    }
+
+    fn part2(&self, grids: &Self::Input) -> Answer {
+        summarize_grids(grids, 1).into()
+    }
+}
+
+fn summarize_grids(grids: &[Grid<char>], expected_smudges: usize) -> usize {
+    grids
+        .iter()
+        .map(|grid| summarize_grid(grid, expected_smudges))
+        .sum()
+}
+
+fn summarize_grid(grid: &Grid<char>, expected_smudges: usize) -> usize {
+    if let Some(axis) = reflection(grid, expected_smudges) {
+        return 100 * axis;
+    }
+    reflection(&grid.transpose(), expected_smudges).unwrap_or(0)
+}
+
+/// The row above which `grid` is mirrored with exactly `expected_smudges` mismatches
+fn reflection(grid: &Grid<char>, expected_smudges: usize) -> Option<usize> {
+    (1..grid.height()).find(|axis| smudges(grid, *axis) == expected_smudges)
+}
+
+fn smudges(grid: &Grid<char>, axis: usize) -> usize {
+    let rows = grid.rows().collect_vec();
+    rows[..axis]
+        .iter()
+        .rev()
+        .zip(&rows[axis..])
+        .map(|(a, b)| a.iter().zip(b.iter()).filter(|(a, b)| a != b).count())
+        .sum()
+}
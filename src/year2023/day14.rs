@@ -0,0 +1,134 @@
+use crate::solution::{Answer, Solution};
+use crate::util::plot::Frame;
+use crate::util::{AdventHelper, BitSetGrid, Colour, Grid, ParseError, Point, Renderer};
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+
+const ANIMATED_CYCLES: usize = 3;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<char>;
+
+    const PART1: &'static str = "load:  {}";
+    const PART2: &'static str = "load:  {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.grid()
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Answer {
+        load(grid).into()
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Answer {
+        load2(grid, 1_000_000_000).into()
+    }
+
+    fn visualize<'a>(&self, grid: &'a Self::Input) -> Option<Renderer<'a>> {
+        let (fixed_rocks, round_rocks) = tilted_north(grid);
+        let renderer = Renderer::new(grid.bounds(), move |p| {
+            if round_rocks.contains(&p) {
+                'O'
+            } else if fixed_rocks.contains(&p) {
+                '#'
+            } else {
+                '.'
+            }
+        })
+        .colours(|c| match c {
+            'O' => Some(Colour::Yellow),
+            '#' => Some(Colour::Grey),
+            _ => None,
+        });
+        Some(renderer)
+    }
+
+    fn frames(&self, grid: &Self::Input) -> Vec<Frame> {
+        let fixed_rocks = BitSetGrid::from_grid(grid, |c| *c == '#');
+        let mut round_rocks = BitSetGrid::from_grid(grid, |c| *c == 'O');
+        let frame = |round_rocks: &BitSetGrid| {
+            Frame::new(grid.bounds())
+                .points(&fixed_rocks, Colour::Grey)
+                .points(round_rocks, Colour::Yellow)
+        };
+
+        let mut frames = vec![frame(&round_rocks)];
+        for _ in 0..ANIMATED_CYCLES {
+            for mv in [Point::up, Point::left, Point::down, Point::right] {
+                step(&mut round_rocks, mv, &fixed_rocks);
+                frames.push(frame(&round_rocks));
+            }
+        }
+        frames
+    }
+}
+
+fn load(grid: &Grid<char>) -> i32 {
+    let n = grid.height() as i32;
+    let (_, round_rocks) = tilted_north(grid);
+    round_rocks.into_iter().map(|p| n - p.y).sum()
+}
+
+/// The fixed and the round rocks after tilting the platform north
+fn tilted_north(grid: &Grid<char>) -> (BitSetGrid, BitSetGrid) {
+    let fixed_rocks = BitSetGrid::from_grid(grid, |c| *c == '#');
+    let mut round_rocks = BitSetGrid::from_grid(grid, |c| *c == 'O');
+
+    step(&mut round_rocks, Point::up, &fixed_rocks);
+    (fixed_rocks, round_rocks)
+}
+
+fn load2(grid: &Grid<char>, target: i32) -> i32 {
+    let n = grid.height() as i32;
+    let mut seen: FxHashMap<String, i32> = FxHashMap::default();
+    let mut loads: FxHashMap<i32, i32> = FxHashMap::default();
+    let fixed_rocks = BitSetGrid::from_grid(grid, |c| *c == '#');
+    let mut round_rocks = BitSetGrid::from_grid(grid, |c| *c == 'O');
+
+    for i in 1.. {
+        step(&mut round_rocks, Point::up, &fixed_rocks);
+        step(&mut round_rocks, Point::left, &fixed_rocks);
+        step(&mut round_rocks, Point::down, &fixed_rocks);
+        step(&mut round_rocks, Point::right, &fixed_rocks);
+
+        let digest = round_rocks.into_iter().sorted().join(",");
+        let load = round_rocks.into_iter().map(|p| n - p.y).sum();
+        if let Some(idx) = seen.get(&digest).cloned() {
+            let cycle_length = i - idx;
+            let distance_to_target = (target - idx) % cycle_length;
+            return loads[&(idx + distance_to_target)];
+        }
+        seen.insert(digest, i);
+        loads.insert(i, load);
+    }
+    unreachable!()
+}
+
+fn step(round_rocks: &mut BitSetGrid, mv: fn(&Point) -> Point, fixed_rocks: &BitSetGrid) {
+    let bounds = fixed_rocks.bounds();
+    let mut changed = 1;
+    while changed > 0 {
+        changed = 0;
+        let map = round_rocks.into_iter().collect_vec();
+        for p in map {
+            let mut good = p;
+            loop {
+                let next = mv(&good);
+                if !bounds.contains(&next)
+                    || fixed_rocks.contains(&next)
+                    || round_rocks.contains(&next)
+                {
+                    break;
+                }
+                good = next;
+            }
+            if good != p {
+                round_rocks.remove(&p);
+                round_rocks.insert(&good);
+                changed += 1;
+            }
+        }
+    }
+}
@@ -1,13 +1,26 @@
-use crate::util::AdventHelper;
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, ParseError};
 use itertools::{repeat_n, Itertools};
 
-pub fn main() {
-    let advent = AdventHelper::from_file_name(file!());
-    let instructions_raw = advent.parse_from_strings::<String>()[0].clone();
-    let instructions = instructions_raw.split(",").collect_vec();
+pub struct Day15;
 
-    advent.part1("checksum:  {}", checksum(instructions.clone()));
-    advent.part2("power:  {}", focusing_power(instructions.clone()));
+impl Solution for Day15 {
+    type Input = String;
+
+    const PART1: &'static str = "checksum:  {}";
+    const PART2: &'static str = "power:  {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        Ok(advent.parse_from_strings::<String>()?[0].clone())
+    }
+
+    fn part1(&self, instructions_raw: &Self::Input) -> Answer {
+        checksum(instructions_raw.split(',').collect_vec()).into()
+    }
+
+    fn part2(&self, instructions_raw: &Self::Input) -> Answer {
+        focusing_power(instructions_raw.split(',').collect_vec()).into()
+    }
 }
 
 fn checksum(instructions: Vec<&str>) -> usize {
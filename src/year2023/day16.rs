@@ -0,0 +1,140 @@
+use crate::solution::{Answer, Solution};
+use crate::util::plot::Frame;
+use crate::util::Dir::{Down, Left, Right, Up};
+use crate::util::{AdventHelper, Colour, Dir, Grid, ParseError, Point, Renderer};
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+
+const MAX_FRAMES: usize = 60;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<char>;
+
+    const PART1: &'static str = "number of energized:  {}";
+    const PART2: &'static str = "number of energized:  {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.grid()
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Answer {
+        count_energized(grid, Point::new(0, 0), Right).into()
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Answer {
+        max_energized(grid).into()
+    }
+
+    fn visualize<'a>(&self, grid: &'a Self::Input) -> Option<Renderer<'a>> {
+        let energized = energized(grid, Point::new(0, 0), Right);
+        let empty = energized.iter().filter(|p| grid[**p] == '.').copied();
+        let renderer = Renderer::grid(grid, |c| *c)
+            .highlight(energized.iter().copied(), Colour::Yellow)
+            .overlay(empty, '#', Colour::Yellow);
+        Some(renderer)
+    }
+
+    fn frames(&self, grid: &Self::Input) -> Vec<Frame> {
+        let fronts = beam_fronts(grid, Point::new(0, 0), Right);
+        let every = (fronts.len() / MAX_FRAMES).max(1);
+        let mut energized = FxHashSet::default();
+        let mut frames = vec![];
+        for (i, front) in fronts.iter().enumerate() {
+            energized.extend(front.iter().copied());
+            if i % every == 0 || i == fronts.len() - 1 {
+                let frame = Frame::grid(grid, |c| (*c != '.').then_some(Colour::Grey))
+                    .points(energized.iter().copied(), Colour::Yellow)
+                    .points(front.iter().copied(), Colour::Red);
+                frames.push(frame);
+            }
+        }
+        frames
+    }
+}
+
+fn max_energized(grid: &Grid<char>) -> usize {
+    let max_y = grid.height() - 1;
+    let max_x = grid.width() - 1;
+
+    let top_down = (0..=max_x)
+        .into_par_iter()
+        .map(|x| count_energized(grid, Point::new(x, 0), Down));
+    let bottom_up = (0..=max_x)
+        .into_par_iter()
+        .map(|x| count_energized(grid, Point::new(x, max_y), Up));
+    let left_right = (0..=max_y)
+        .into_par_iter()
+        .map(|y| count_energized(grid, Point::new(0, y), Right));
+    let right_left = (0..=max_y)
+        .into_par_iter()
+        .map(|y| count_energized(grid, Point::new(max_x, y), Left));
+    top_down
+        .chain(bottom_up)
+        .chain(left_right)
+        .chain(right_left)
+        .max()
+        .unwrap()
+}
+
+fn count_energized(grid: &Grid<char>, start: Point, dir: Dir) -> usize {
+    energized(grid, start, dir).len()
+}
+
+fn energized(grid: &Grid<char>, start: Point, dir: Dir) -> FxHashSet<Point> {
+    let capacity = grid.width() * grid.height();
+    let initial = (start, dir);
+    let mut visited = FxHashSet::with_capacity_and_hasher(capacity, Default::default());
+    let mut visited_point = FxHashSet::with_capacity_and_hasher(capacity, Default::default());
+    let mut front = vec![initial];
+    while let Some(current @ (point, dir)) = front.pop() {
+        let Some(tile) = grid.get(&point) else {
+            continue;
+        };
+        if visited.contains(&current) {
+            continue;
+        }
+        visited.insert(current);
+        visited_point.insert(point);
+        for new_dir in reflect(*tile, dir) {
+            front.push((point.mv(new_dir), new_dir))
+        }
+    }
+
+    visited_point
+}
+
+/// The tiles at the heads of the beams after each step, until the beams reach no new tiles
+fn beam_fronts(grid: &Grid<char>, start: Point, dir: Dir) -> Vec<Vec<Point>> {
+    let mut visited = FxHashSet::default();
+    let mut front = vec![(start, dir)];
+    let mut fronts = vec![];
+    loop {
+        front.retain(|state @ (point, _)| grid.contains(point) && visited.insert(*state));
+        if front.is_empty() {
+            return fronts;
+        }
+        fronts.push(front.iter().map(|(point, _)| *point).collect());
+        front = front
+            .iter()
+            .flat_map(|(point, dir)| {
+                reflect(grid[*point], *dir)
+                    .into_iter()
+                    .map(|new_dir| (point.mv(new_dir), new_dir))
+            })
+            .collect();
+    }
+}
+
+fn reflect(tile: char, dir: Dir) -> Vec<Dir> {
+    match (dir, tile) {
+        (Up, '/') | (Down, '\\') => vec![Right],
+        (Down, '/') | (Up, '\\') => vec![Left],
+        (Right, '/') | (Left, '\\') => vec![Up],
+        (Left, '/') | (Right, '\\') => vec![Down],
+        (Up, '-') | (Down, '-') => vec![Left, Right],
+        (Left, '|') | (Right, '|') => vec![Up, Down],
+        (d, _) => vec![d],
+    }
+}
@@ -0,0 +1,119 @@
+use crate::solution::{Answer, Solution};
+use crate::util::plot::Frame;
+use crate::util::Dir::{Down, Left, Right, Up};
+use crate::util::{AdventHelper, Colour, Dir, Grid, ParseError, Point, Renderer};
+use itertools::Itertools;
+use pathfinding::prelude::dijkstra;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<i32>;
+
+    const PART1: &'static str = "Minimal Heat Loss:  {}";
+    const PART2: &'static str = "Minimal Heat Loss:  {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        Ok(advent.parse_from_grid().into())
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Answer {
+        find_cheapest(grid, 0, 3).into()
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Answer {
+        find_cheapest(grid, 4, 10).into()
+    }
+
+    fn visualize<'a>(&self, grid: &'a Self::Input) -> Option<Renderer<'a>> {
+        let (path, _) = cheapest_path(grid, 0, 3);
+        let points = path.iter().map(|state| state.point).collect_vec();
+        let renderer = Renderer::grid(grid, |n| char::from_digit(*n as u32, 10).unwrap())
+            .colours(|_| Some(Colour::Grey))
+            .path(&points, Colour::Red);
+        Some(renderer)
+    }
+
+    fn frames(&self, grid: &Self::Input) -> Vec<Frame> {
+        let (path, _) = cheapest_path(grid, 0, 3);
+        let points = path.iter().map(|state| state.point).collect_vec();
+        let frame = Frame::grid(grid, |n| match n {
+            1..=3 => None,
+            4..=6 => Some(Colour::Grey),
+            _ => Some(Colour::Blue),
+        })
+        .path(&points, Colour::Red);
+        vec![frame]
+    }
+}
+
+fn find_cheapest(grid: &Grid<i32>, min_streak: i32, max_streak: i32) -> i32 {
+    let (_, minimal_heat_loss) = cheapest_path(grid, min_streak, max_streak);
+    minimal_heat_loss
+}
+
+fn cheapest_path(grid: &Grid<i32>, min_streak: i32, max_streak: i32) -> (Vec<State>, i32) {
+    let start = State {
+        point: Point::new(0, 0),
+        streak: 0,
+        orientation: None,
+    };
+    let target = Point::new(grid.width() - 1, grid.height() - 1);
+    dijkstra(
+        &start,
+        |current| successors(current, grid, min_streak, max_streak),
+        |state| state.point == target,
+    )
+    .unwrap()
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct State {
+    point: Point,
+    streak: i32,
+    orientation: Option<Dir>,
+}
+fn successors(
+    current: &State,
+    grid: &Grid<i32>,
+    min_streak: i32,
+    max_streak: i32,
+) -> Vec<(State, i32)> {
+    let State {
+        point: p,
+        streak,
+        orientation,
+    } = current;
+    let mut successors = vec![];
+    let can_turn = *streak >= min_streak;
+    let can_stay = *streak < max_streak;
+    for new_dir in [Up, Right, Down, Left] {
+        let candidate = p.mv(new_dir);
+        let Some(cost) = grid.get(&candidate) else {
+            continue;
+        };
+        let is_pivot = Some(new_dir.pivot()) == *orientation;
+        if is_pivot {
+            continue;
+        }
+
+        let is_same_direction = Some(new_dir) == *orientation;
+        let is_valid_stay = can_stay && is_same_direction;
+
+        let is_valid_turn = can_turn && Some(new_dir) != *orientation;
+        let new_streak = if is_valid_turn { 1 } else { streak + 1 };
+
+        if is_valid_stay || is_valid_turn || orientation.is_none() {
+            successors.push((
+                State {
+                    point: candidate,
+                    streak: new_streak,
+                    orientation: Some(new_dir),
+                },
+                *cost,
+            ));
+        }
+    }
+
+    successors
+}
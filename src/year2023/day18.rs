@@ -0,0 +1,195 @@
+use crate::scan;
+use crate::solution::{Answer, Solution};
+use crate::util::compression::GridCompression;
+use crate::util::geometry::lattice_points;
+use crate::util::plot::Frame;
+use crate::util::{AdventHelper, Bounds, Colour, Dir, IntoUnsafe, Line, ParseError, Point};
+use itertools::Itertools;
+use num::Num;
+use pathfinding::prelude::dfs_reach;
+use rustc_hash::FxHashSet;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<DigInstruction>;
+
+    const PART1: &'static str = "Inside Area: {}";
+    const PART2: &'static str = "Inside Area: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.parse_from_strings()
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Answer {
+        lagoon_size_by_pick(instructions).into()
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> Answer {
+        let real_instructions = instructions.iter().map(|x| x.real()).collect_vec();
+        lagoon_size_by_pick(&real_instructions).into()
+    }
+
+    fn frames(&self, instructions: &Self::Input) -> Vec<Frame> {
+        let corners = corner_points(instructions);
+        let corner_set: FxHashSet<Point> = corners.iter().copied().collect();
+        let bounds = Point::bounds(&corner_set).expand(1);
+        vec![Frame::new(bounds).polygon(&corners, Colour::Yellow)]
+    }
+}
+
+/// The lagoon size counted from the corners alone, using Pick's theorem
+pub fn lagoon_size_by_pick(instructions: &[DigInstruction]) -> i64 {
+    lattice_points(&corner_points::<i64>(instructions))
+}
+
+/// The lagoon size via compressed coordinates and a flood fill of the outside
+pub fn lagoon_size_by_flood_fill(instructions: &[DigInstruction]) -> i64 {
+    let corners = corner_points::<i64>(instructions);
+    let compression = GridCompression::new(&corners);
+
+    let border = compressed_border(instructions, &corners, &compression);
+    let bounds = Point::bounds(&border);
+    let outside = flood_fill_outside(&border, &bounds);
+
+    bounds
+        .into_iter()
+        .filter(|p| !outside.contains(p))
+        .map(|p| compression.area(&p))
+        .sum()
+}
+
+fn corner_points<T: Copy + Num>(instructions: &[DigInstruction]) -> Vec<Point<T>>
+where
+    usize: IntoUnsafe<T>,
+{
+    let mut current = Point {
+        x: T::zero(),
+        y: T::zero(),
+    };
+    let mut corners = vec![current];
+    for instruction in instructions {
+        current = current.mv_mulitple(instruction.dir, instruction.length);
+        corners.push(current);
+    }
+    corners
+}
+
+fn compressed_border(
+    instructions: &[DigInstruction],
+    corners: &[Point<i64>],
+    compression: &GridCompression<i64>,
+) -> HashSet<Point> {
+    let mut border = HashSet::new();
+    for (instruction, (from, to)) in instructions.iter().zip(corners.iter().tuple_windows()) {
+        let mut current = compression.compress(from);
+        let next = compression.compress(to);
+        while current != next {
+            border.insert(current);
+            current = current.mv(instruction.dir)
+        }
+        border.insert(next);
+    }
+    border
+}
+
+fn flood_fill_outside(border: &HashSet<Point>, bounds1: &Bounds) -> HashSet<Point> {
+    let bounds: Bounds = bounds1.expand(1);
+    let outside_point = Point::new(bounds.min_x, bounds.min_y);
+
+    let outside: HashSet<Point> = dfs_reach(outside_point, |p| {
+        p.neighbours()
+            .into_iter()
+            .filter(|n| !border.contains(n) && bounds.contains(n))
+            .collect_vec()
+    })
+    .collect();
+    outside
+}
+
+#[derive(Debug)]
+pub struct DigInstruction {
+    dir: Dir,
+    length: usize,
+    /// The direction and length hidden in the colour code
+    real: (Dir, usize),
+}
+
+impl DigInstruction {
+    fn real(&self) -> DigInstruction {
+        let (dir, length) = self.real;
+        DigInstruction {
+            dir,
+            length,
+            real: self.real,
+        }
+    }
+}
+
+impl FromStr for DigInstruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = Line(s);
+        let (dir, length, color) = scan!(
+            line,
+            "{dir} {length} (#{color})",
+            dir: Dir,
+            length: usize,
+            color: &str
+        )?;
+        if color.len() != 6 || !color.is_ascii() {
+            return Err(line.error(color, "expected 6 hex digits, found"));
+        }
+        let (hex_length, hex_dir) = color.split_at(5);
+        if !hex_length.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(line.error(hex_length, "expected 5 hex digits, found"));
+        }
+        let real_length = usize::from_str_radix(hex_length, 16).unwrap();
+        let real_dir = match hex_dir {
+            "0" | "1" | "2" | "3" => line.parse(hex_dir)?,
+            _ => return Err(line.error(hex_dir, "expected a direction digit 0-3, found")),
+        };
+        Ok(DigInstruction {
+            dir,
+            length,
+            real: (real_dir, real_length),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::{parse_lines, AdventHelper, InputSource};
+    use crate::year2023::day18::{lagoon_size_by_flood_fill, lagoon_size_by_pick, DigInstruction};
+    use itertools::Itertools;
+
+    #[test]
+    fn measures_lagoons_both_ways() {
+        let advent = AdventHelper::with_source(2023, 18, InputSource::Example(1));
+        let instructions: Vec<DigInstruction> = parse_lines(&advent.read_input().unwrap()).unwrap();
+        let real_instructions = instructions.iter().map(|x| x.real()).collect_vec();
+
+        assert_eq!(lagoon_size_by_pick(&instructions), 62);
+        assert_eq!(lagoon_size_by_flood_fill(&instructions), 62);
+        assert_eq!(lagoon_size_by_pick(&real_instructions), 952408144115);
+        assert_eq!(lagoon_size_by_flood_fill(&real_instructions), 952408144115);
+    }
+
+    #[test]
+    fn rejects_malformed_colours() {
+        let error = |s: &str| s.parse::<DigInstruction>().unwrap_err();
+
+        assert!("R 6 (#70c710)".parse::<DigInstruction>().is_ok());
+        assert_eq!(error("R 6 (#70c71)").column, 7);
+        assert_eq!(
+            error("R 6 (#zzzzz0)").message,
+            "expected 5 hex digits, found"
+        );
+        assert_eq!(error("R 6 (#zzzzz0)").column, 7);
+        let error = error("R 6 (#70c714)");
+        assert_eq!((error.column, error.text.as_str()), (12, "4"));
+    }
+}
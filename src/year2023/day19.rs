@@ -1,18 +1,31 @@
-use crate::day19::Action::{Accept, Call, Reject};
-use crate::day19::Case::{Any, GreaterThan, LessThan};
-use crate::util::AdventHelper;
-use itertools::Itertools;
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, Line, ParseError};
+use crate::year2023::day19::Action::{Accept, Call, Reject};
+use crate::year2023::day19::Case::{Any, GreaterThan, LessThan};
 use std::cmp::{max, min};
 use std::ops::RangeInclusive;
 
 use rustc_hash::FxHashMap;
 
-pub fn main() {
-    let advent = AdventHelper::from_file_name(file!());
-    let system: Syst = parse_system(&advent.parse_from_strings());
+pub struct Day19;
 
-    advent.part1("Count accepted: {}", count_accepted(&system));
-    advent.part2("All accepted: {}", count_total_accepted(&system.functions))
+impl Solution for Day19 {
+    type Input = Syst;
+
+    const PART1: &'static str = "Count accepted: {}";
+    const PART2: &'static str = "All accepted: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        parse_system(advent)
+    }
+
+    fn part1(&self, system: &Self::Input) -> Answer {
+        count_accepted(system).into()
+    }
+
+    fn part2(&self, system: &Self::Input) -> Answer {
+        count_total_accepted(&system.functions).into()
+    }
 }
 
 fn count_total_accepted(map: &FxHashMap<String, Function>) -> u64 {
@@ -162,52 +175,84 @@ fn is_accepted(part: &Part, map: &FxHashMap<String, Function>) -> bool {
     }
 }
 
-fn parse_system(lines: &Vec<String>) -> Syst {
-    let (raw_functions, raw_parts) = lines.split(|l| l.is_empty()).collect_tuple().unwrap();
-    Syst {
-        functions: raw_functions.iter().map(|f| parse_function(f)).collect(),
-        parts: raw_parts.iter().map(|p| parse_part(p)).collect(),
-    }
+fn parse_system(advent: &AdventHelper) -> Result<Syst, ParseError> {
+    let mut system = Syst {
+        functions: FxHashMap::default(),
+        parts: vec![],
+    };
+    advent.parse_sections(|section| {
+        match section.index {
+            0 => {
+                system.functions = section
+                    .parse_lines_with(parse_function)?
+                    .into_iter()
+                    .collect()
+            }
+            1 => system.parts = section.parse_lines_with(parse_part)?,
+            _ => {
+                let line = Line(&section.lines[0]);
+                let error = line.error(line.0, "expected workflows and ratings only, found");
+                return Err(error.at_line(section.line_number(0)));
+            }
+        }
+        Ok(())
+    })?;
+    Ok(system)
 }
 
-fn parse_part(raw_part: &String) -> Part {
-    raw_part
-        .strip_prefix('{')
-        .unwrap()
-        .strip_suffix('}')
-        .unwrap()
+fn parse_part(line: Line) -> Result<Part, ParseError> {
+    let ratings = line.strip_prefix(line.0, "{")?;
+    let ratings = line.strip_suffix(ratings, "}")?;
+    let part: Part = ratings
         .split(',')
-        .map(|s| (s.chars().next().unwrap(), s[2..].parse().unwrap()))
-        .collect()
+        .map(|rating| {
+            let (category, value) = line.split_once(rating, "=")?;
+            Ok((parse_category(line, category)?, line.parse(value)?))
+        })
+        .collect::<Result<_, ParseError>>()?;
+    if part.len() != 4 {
+        return Err(line.error(
+            ratings,
+            "expected one rating for each of x, m, a and s, found",
+        ));
+    }
+    Ok(part)
 }
 
-fn parse_function(raw_function: &String) -> (String, Function) {
-    let (name, raw_body) = raw_function
-        .strip_suffix('}')
-        .unwrap()
-        .split('{')
-        .collect_tuple()
-        .unwrap();
-    let cases = raw_body.split(',').map(|s| parse_case(s)).collect_vec();
-    (name.to_string(), Function { cases })
+fn parse_function(line: Line) -> Result<(String, Function), ParseError> {
+    let (name, raw_body) = line.split_once(line.0, "{")?;
+    let raw_body = line.strip_suffix(raw_body, "}")?;
+    let cases = raw_body
+        .split(',')
+        .map(|raw_case| parse_case(line, raw_case))
+        .collect::<Result<_, _>>()?;
+    Ok((name.to_string(), Function { cases }))
 }
 
-fn parse_case(raw_case: &str) -> Case {
+fn parse_case(line: Line, raw_case: &str) -> Result<Case, ParseError> {
     //tq{s<1378:A,x>1056:A,x<975:A,R}
-    let split = raw_case.split([':']).collect_vec();
-    match &split[..] {
-        [action] => Any(parse_action(*action)),
-        [condition, action] if condition.contains('<') => LessThan(
-            condition.chars().next().unwrap(),
-            condition[2..].parse().unwrap(),
-            parse_action(*action),
-        ),
-        [condition, action] if condition.contains('>') => GreaterThan(
-            condition.chars().next().unwrap(),
-            condition[2..].parse().unwrap(),
-            parse_action(*action),
-        ),
-        _ => panic!(),
+    let Some((condition, action)) = raw_case.split_once(':') else {
+        return Ok(Any(parse_action(raw_case)));
+    };
+    let category = parse_category(line, condition.get(..1).unwrap_or(condition))?;
+    let comparison = &condition[1..];
+    match (comparison.get(..1), comparison.get(1..)) {
+        (Some("<"), Some(value)) => {
+            Ok(LessThan(category, line.parse(value)?, parse_action(action)))
+        }
+        (Some(">"), Some(value)) => Ok(GreaterThan(
+            category,
+            line.parse(value)?,
+            parse_action(action),
+        )),
+        _ => Err(line.error(comparison, "expected `<` or `>` and a rating, found")),
+    }
+}
+
+fn parse_category(line: Line, s: &str) -> Result<char, ParseError> {
+    match s {
+        "x" | "m" | "a" | "s" => Ok(s.chars().next().unwrap()),
+        _ => Err(line.error(s, "expected one of x, m, a or s, found")),
     }
 }
 
@@ -219,7 +264,7 @@ fn parse_action(raw_action: &str) -> Action {
     }
 }
 
-struct Syst {
+pub struct Syst {
     functions: FxHashMap<String, Function>,
     parts: Vec<Part>,
 }
@@ -241,3 +286,23 @@ enum Action {
 }
 
 type Part = FxHashMap<char, i32>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_malformed_workflows_and_ratings() {
+        let error = parse_part(Line("{x=zz}")).err().unwrap();
+        assert_eq!((error.column, error.text.as_str()), (4, "zz"));
+        let error = parse_part(Line("{x=1,m=2,a=3}")).err().unwrap();
+        assert_eq!(error.column, 2);
+        let error = parse_function(Line("px{a<2006:qkq,q>2090:A,rfg}"))
+            .err()
+            .unwrap();
+        assert_eq!((error.column, error.text.as_str()), (15, "q"));
+        let error = parse_function(Line("px{a=2006:qkq,R}")).err().unwrap();
+        assert_eq!((error.column, error.text.as_str()), (5, "=2006"));
+        assert!(parse_function(Line("px{a<2006:qkq,m>2090:A,rfg}")).is_ok());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::scan;
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, Line, ParseError};
+use itertools::Itertools;
+use std::str::FromStr;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+
+    const PART1: &'static str = "Number of possible games: {}";
+    const PART2: &'static str = "Minimum Power: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.parse_from_strings()
+    }
+
+    fn part1(&self, games: &Self::Input) -> Answer {
+        sum_valid_games(games, 12, 13, 14).into()
+    }
+
+    fn part2(&self, games: &Self::Input) -> Answer {
+        sum_minimum_powers(games).into()
+    }
+}
+
+fn sum_minimum_powers(games: &[Game]) -> usize {
+    games.iter().map(|game| game.minimum_power()).sum()
+}
+
+fn sum_valid_games(games: &[Game], max_red: usize, max_green: usize, max_blue: usize) -> u32 {
+    games
+        .iter()
+        .filter(|game| game.is_valid(max_red, max_green, max_blue))
+        .map(|game| game.id)
+        .sum()
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Round {
+    red: usize,
+    green: usize,
+    blue: usize,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Game {
+    id: u32,
+    rounds: Vec<Round>,
+}
+
+impl Game {
+    fn is_valid(&self, max_red: usize, max_green: usize, max_blue: usize) -> bool {
+        self.rounds
+            .iter()
+            .all(|round| max_red >= round.red && max_green >= round.green && max_blue >= round.blue)
+    }
+
+    fn minimum_power(&self) -> usize {
+        let min_red = self.rounds.iter().map(|round| round.red).max().unwrap();
+        let min_green = self.rounds.iter().map(|round| round.green).max().unwrap();
+        let min_blue = self.rounds.iter().map(|round| round.blue).max().unwrap();
+
+        min_red * min_green * min_blue
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = Line(s);
+        let (id, raw_rounds_str) = scan!(line, "Game {id}: {rounds}", id: u32, rounds: &str)?;
+        let rounds = raw_rounds_str
+            .split("; ")
+            .map(|raw_round| parse_round(line, raw_round))
+            .try_collect()?;
+        Ok(Game { id, rounds })
+    }
+}
+
+fn parse_round(line: Line, s: &str) -> Result<Round, ParseError> {
+    let raw_colours = s.split(", ");
+    let mut red = 0;
+    let mut green = 0;
+    let mut blue = 0;
+    for raw_colour in raw_colours {
+        let (num, colour) = scan!(line, raw_colour, "{num} {colour}", num: usize, colour: &str)?;
+        match colour {
+            "red" => red = num,
+            "green" => green = num,
+            "blue" => blue = num,
+            _ => return Err(line.error(colour, "expected red, green or blue, found")),
+        }
+    }
+
+    Ok(Round { red, green, blue })
+}
@@ -1,26 +1,40 @@
-use crate::util::AdventHelper;
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, ParseError};
 use itertools::Itertools;
 use num::integer::lcm;
 use std::collections::VecDeque;
 use std::fmt::Debug;
 
-use crate::day20::Module::{Broadcaster, Conjunction, FlipFlop};
-use crate::day20::Signal::{High, Low};
+use crate::year2023::day20::Module::{Broadcaster, Conjunction, FlipFlop};
+use crate::year2023::day20::Signal::{High, Low};
 use rustc_hash::FxHashMap;
 
-pub fn main() {
-    let advent = AdventHelper::from_file_name(file!());
-    let mut system: MachineSystem = parse_system(&advent.parse_from_strings());
-    let mut system2: MachineSystem = parse_system(&advent.parse_from_strings());
+pub struct Day20;
 
-    advent.part1("Pulse product: {}", system.push_button(1000, false));
-    advent.part2(
-        "Pulse product: {}",
-        system2.push_button(10000000000000, true),
-    )
+impl Solution for Day20 {
+    type Input = MachineSystem;
+
+    const PART1: &'static str = "Pulse product: {}";
+    const PART2: &'static str = "Pulse product: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        Ok(parse_system(&advent.parse_from_strings()?))
+    }
+
+    fn part1(&self, system: &Self::Input) -> Answer {
+        system.clone().push_button(1000, false).into()
+    }
+
+    fn part2(&self, system: &Self::Input) -> Answer {
+        // The examples have no `rx` module to wait for
+        if system.name_before_rx().is_none() {
+            return Answer::Missing;
+        }
+        system.clone().push_button(10000000000000, true).into()
+    }
 }
 
-fn parse_system(input: &Vec<String>) -> MachineSystem {
+fn parse_system(input: &[String]) -> MachineSystem {
     let mut modules = FxHashMap::default();
     let mut destinations = FxHashMap::default();
     for line in input {
@@ -60,21 +74,24 @@ fn parse_system(input: &Vec<String>) -> MachineSystem {
     }
 }
 
-struct MachineSystem {
+#[derive(Clone)]
+pub struct MachineSystem {
     modules: FxHashMap<String, Module>,
     destinations: FxHashMap<String, Vec<String>>,
 }
 
 impl MachineSystem {
+    fn name_before_rx(&self) -> Option<&String> {
+        self.destinations
+            .iter()
+            .find(|(_, ds)| ds.contains(&"rx".to_string()))
+            .map(|(name, _)| name)
+    }
+
     pub(crate) fn push_button(&mut self, times: usize, exit_on_rx: bool) -> usize {
         let mut low_count = 0;
         let mut high_count = 0;
-        let name_before_rx = self
-            .destinations
-            .iter()
-            .find(|(_, ds)| ds.contains(&"rx".to_string()))
-            .unwrap()
-            .0;
+        let name_before_rx = self.name_before_rx().cloned();
         let mut periods = FxHashMap::default();
         for button_presses in 1..=times {
             let mut pulses =
@@ -94,7 +111,7 @@ impl MachineSystem {
                         ref mut latest_signals,
                     } => {
                         if exit_on_rx
-                            && cur == *name_before_rx
+                            && name_before_rx.as_ref() == Some(&cur)
                             && signal == High
                             && !periods.contains_key(&src)
                         {
@@ -133,7 +150,7 @@ impl MachineSystem {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Module {
     Conjunction {
         latest_signals: FxHashMap<String, Signal>,
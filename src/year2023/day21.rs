@@ -1,34 +1,44 @@
-use crate::util::{AdventHelper, Point};
-use array2d::Array2D;
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, Grid, ParseError, Point};
 use num::Integer;
 use rustc_hash::{FxHashMap, FxHashSet};
 
-pub fn main() {
-    let advent = AdventHelper::from_file_name(file!());
-    let garden = Array2D::from_rows(&advent.parse_grid()).unwrap();
+pub struct Day21;
 
-    advent.part1("Reachable plots: {}", reachable_plots(&garden, 64));
-    advent.part2("Reachable plots: {}", reachable_plots(&garden, 26501365));
+impl Solution for Day21 {
+    type Input = Grid<char>;
+
+    const PART1: &'static str = "Reachable plots: {}";
+    const PART2: &'static str = "Reachable plots: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.grid()
+    }
+
+    fn part1(&self, garden: &Self::Input) -> Answer {
+        reachable_plots(garden, 64).into()
+    }
+
+    fn part2(&self, garden: &Self::Input) -> Answer {
+        reachable_plots(garden, 26501365).into()
+    }
 }
 
-fn reachable_plots(garden: &Array2D<char>, steps: usize) -> usize {
-    let ((y, x), _) = garden
-        .enumerate_row_major()
-        .find(|((_y, _x), c)| **c == 'S')
-        .unwrap();
+fn reachable_plots(garden: &Grid<char>, steps: usize) -> usize {
+    let Point { x, y } = garden.find(|c| *c == 'S').unwrap();
     let mut cache = FxHashMap::default();
     let mut total = 0;
     let mut top_remaining = (steps as i64) - y as i64 - 1;
     while top_remaining > 0 {
         total += reachable_plots_row(
-            &Point::new(x, garden.column_len() - 1),
+            &Point::new(x, garden.height() - 1),
             garden,
             top_remaining as usize,
             &mut cache,
         );
-        top_remaining -= garden.column_len() as i64;
+        top_remaining -= garden.height() as i64;
     }
-    let mut bottom_remaining = steps as i64 - (garden.column_len() as i64 - y as i64);
+    let mut bottom_remaining = steps as i64 - (garden.height() as i64 - y as i64);
 
     while bottom_remaining > 0 {
         total += reachable_plots_row(
@@ -37,7 +47,7 @@ fn reachable_plots(garden: &Array2D<char>, steps: usize) -> usize {
             bottom_remaining as usize,
             &mut cache,
         );
-        bottom_remaining -= garden.column_len() as i64;
+        bottom_remaining -= garden.height() as i64;
     }
     total += reachable_plots_row(&Point::new(x, y), garden, steps, &mut cache);
     total
@@ -45,11 +55,11 @@ fn reachable_plots(garden: &Array2D<char>, steps: usize) -> usize {
 
 fn reachable_plots_row(
     start: &Point,
-    garden: &Array2D<char>,
+    garden: &Grid<char>,
     steps: usize,
     cache: &mut FxHashMap<(Point, usize), usize>,
 ) -> usize {
-    let modulus = garden.row_len();
+    let modulus = garden.width();
     let base = steps % modulus;
     let first = base + modulus;
     let second = base + 2 * modulus;
@@ -70,13 +80,13 @@ fn reachable_plots_row(
     (steps / modulus - 1) * period + first_res
 }
 
-fn reachable_plots_with_wrapped_rows(start: &Point, garden: &Array2D<char>, steps: usize) -> usize {
-    let cap = garden.row_len() * garden.column_len();
-    let mut previous_even = FxHashSet::from_iter([start.clone()]);
+fn reachable_plots_with_wrapped_rows(start: &Point, garden: &Grid<char>, steps: usize) -> usize {
+    let cap = garden.width() * garden.height();
+    let mut previous_even = FxHashSet::from_iter([*start]);
     previous_even.reserve(cap);
     let mut previous_odd = FxHashSet::default();
     previous_odd.reserve(cap);
-    let mut current = FxHashSet::from_iter([start.clone()]);
+    let mut current = FxHashSet::from_iter([*start]);
     for step in 1..=steps {
         current = current
             .iter()
@@ -85,10 +95,7 @@ fn reachable_plots_with_wrapped_rows(start: &Point, garden: &Array2D<char>, step
                 if previous_even.contains(p) || previous_odd.contains(p) {
                     return false;
                 }
-                let tile = garden.get(
-                    p.y as usize,
-                    p.x.rem_euclid(garden.row_len() as i32) as usize,
-                );
+                let tile = garden.get(&Point::new(p.x.rem_euclid(garden.width() as i32), p.y));
                 tile == Some(&'.') || tile == Some(&'S')
             })
             .collect();
@@ -1,76 +1,130 @@
-use crate::util::AdventHelper;
+use crate::scan;
+use crate::solution::{Answer, Solution};
+use crate::util::plot::Frame;
+use crate::util::{AdventHelper, Bounds, Box3, Colour, Line, ParseError, Point, Point3, Vec3};
 use itertools::Itertools;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::str::FromStr;
 
-pub fn main() {
-    let advent = AdventHelper::from_file_name(file!());
-    let blocks = advent.parse_from_strings();
+const MAX_FRAMES: usize = 60;
+const BRICK_COLOURS: [Colour; 6] = [
+    Colour::Red,
+    Colour::Green,
+    Colour::Yellow,
+    Colour::Blue,
+    Colour::Magenta,
+    Colour::Cyan,
+];
 
-    advent.part1("Desintegratable blocks: {}", count_desintegratable(&blocks));
-    advent.part2("Total falling blocks: {}", count_total_falling(&blocks));
-}
+pub struct Day22;
 
-type Point3 = (usize, usize, usize);
-#[derive(Clone)]
-struct Block {
-    start: Point3,
-    end: Point3,
-}
+impl Solution for Day22 {
+    type Input = Vec<Block>;
 
-impl Block {
-    fn layer(&self, z: usize) -> FxHashSet<Point3> {
-        let mut layer = FxHashSet::default();
-        for x in self.start.0..=self.end.0 {
-            for y in self.start.1..=self.end.1 {
-                layer.insert((x, y, z));
+    const PART1: &'static str = "Desintegratable blocks: {}";
+    const PART2: &'static str = "Total falling blocks: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.parse_from_strings()
+    }
+
+    fn part1(&self, blocks: &Self::Input) -> Answer {
+        count_desintegratable(blocks).into()
+    }
+
+    fn part2(&self, blocks: &Self::Input) -> Answer {
+        count_total_falling(blocks).into()
+    }
+
+    fn frames(&self, blocks: &Self::Input) -> Vec<Frame> {
+        let (_, _, settled) = drop(blocks);
+        let order = (0..blocks.len())
+            .sorted_by_key(|i| blocks[*i].cube.min.z)
+            .collect_vec();
+        let every = (order.len() / MAX_FRAMES).max(1);
+        let top = blocks.iter().map(|b| b.cube.max.z).max().unwrap_or(0);
+        let width = blocks.iter().map(|b| b.cube.max.x).max().unwrap_or(0);
+
+        let mut current = blocks.to_vec();
+        let mut frames = vec![side_view(&current, width, top)];
+        for (n, i) in order.iter().enumerate() {
+            current[*i] = settled[*i].clone();
+            if (n + 1) % every == 0 || n + 1 == order.len() {
+                frames.push(side_view(&current, width, top));
             }
         }
-        layer
+        frames
     }
+}
+
+/// The blocks seen from the front, with `z` going up
+fn side_view(blocks: &[Block], width: i32, top: i32) -> Frame {
+    let bounds = Bounds {
+        min_x: 0,
+        max_x: width,
+        min_y: -top,
+        max_y: 0,
+    };
+    blocks
+        .iter()
+        .enumerate()
+        .fold(Frame::new(bounds), |frame, (i, block)| {
+            let cells = (block.cube.min.x..=block.cube.max.x)
+                .cartesian_product(block.cube.min.z..=block.cube.max.z)
+                .map(|(x, z)| Point::new(x, -z));
+            frame.points(cells, BRICK_COLOURS[i % BRICK_COLOURS.len()])
+        })
+}
 
+#[derive(Clone)]
+pub struct Block {
+    cube: Box3,
+}
+
+impl Block {
     fn drop(&self) -> Option<Self> {
-        if self.start.2 == 0 {
+        if self.cube.min.z == 0 {
             return None;
         }
         Some(Block {
-            start: (self.start.0, self.start.1, self.start.2 - 1),
-            end: (self.end.0, self.end.1, self.end.2 - 1),
+            cube: self.cube.shifted(&Vec3::new(0, 0, -1)),
         })
     }
 }
 
 impl FromStr for Block {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (start, end) = s
-            .split('~')
-            .map(|raw| {
-                raw.split(',')
-                    .map(|s| s.parse().unwrap())
-                    .collect_tuple()
-                    .unwrap()
-            })
-            .collect_tuple()
-            .unwrap();
-        Ok(Block { start, end })
+        let (x1, y1, z1, x2, y2, z2) = scan!(
+            Line(s),
+            "{x1},{y1},{z1}~{x2},{y2},{z2}",
+            x1: i32,
+            y1: i32,
+            z1: i32,
+            x2: i32,
+            y2: i32,
+            z2: i32
+        )?;
+        Ok(Block {
+            cube: Box3::new(Point3::new(x1, y1, z1), Point3::new(x2, y2, z2)),
+        })
     }
 }
 
-fn count_desintegratable(blocks: &Vec<Block>) -> usize {
+fn count_desintegratable(blocks: &[Block]) -> usize {
     let map = get_desintegratable(blocks);
     map.iter().filter(|(_k, v)| v.is_empty()).count()
 }
 
-fn count_total_falling(blocks: &Vec<Block>) -> usize {
+fn count_total_falling(blocks: &[Block]) -> usize {
     let n = blocks.len();
-    let mut blocks = blocks.clone();
-    blocks.sort_by_key(|b| b.start.2);
+    let mut blocks = blocks.to_vec();
+    blocks.sort_by_key(|b| b.cube.min.z);
 
-    let (_, rests_on, blocks) = drop(&blocks);
+    let (_, rests_on, _) = drop(&blocks);
 
     let get_below: FxHashMap<usize, FxHashSet<usize>> = rests_on
         .iter()
@@ -114,9 +168,8 @@ fn count_total_falling(blocks: &Vec<Block>) -> usize {
     total
 }
 
-fn get_desintegratable(blocks: &Vec<Block>) -> FxHashMap<usize, Vec<usize>> {
-    let mut blocks = blocks.clone();
-    let (_, rests_on, _) = drop(&mut blocks);
+fn get_desintegratable(blocks: &[Block]) -> FxHashMap<usize, Vec<usize>> {
+    let (_, rests_on, _) = drop(blocks);
 
     let get_below: FxHashMap<usize, Vec<usize>> = rests_on
         .iter()
@@ -144,16 +197,20 @@ fn get_desintegratable(blocks: &Vec<Block>) -> FxHashMap<usize, Vec<usize>> {
     unstable_blocks
 }
 
-fn drop(blocks: &Vec<Block>) -> (usize, FxHashSet<(usize, usize)>, Vec<Block>) {
+fn drop(blocks: &[Block]) -> (usize, FxHashSet<(usize, usize)>, Vec<Block>) {
     let mut layer_above_map = FxHashMap::default();
     let mut rests_on = FxHashSet::default();
     let mut dropped = FxHashSet::default();
     let mut dropped_blocks = vec![];
-    for (i, b) in blocks.iter().enumerate().sorted_by_key(|(_i, b)| b.start.2) {
+    for (i, b) in blocks
+        .iter()
+        .enumerate()
+        .sorted_by_key(|(_i, b)| b.cube.min.z)
+    {
         let mut b = b.clone();
         let mut has_dropped = false;
         loop {
-            for p in b.layer(b.start.2) {
+            for p in b.cube.layer(b.cube.min.z) {
                 if let Some(below_brick_idx) = layer_above_map.get(&p) {
                     rests_on.insert((i, *below_brick_idx));
                     has_dropped = true;
@@ -169,15 +226,14 @@ fn drop(blocks: &Vec<Block>) -> (usize, FxHashSet<(usize, usize)>, Vec<Block>) {
                 break;
             }
         }
-        let set: FxHashSet<Point3> = b.layer(b.end.2 + 1);
-        for p in set {
+        for p in b.cube.layer(b.cube.max.z + 1) {
             layer_above_map.insert(p, i);
         }
         dropped_blocks.push((i, b));
     }
     let dropped_blocks = dropped_blocks
         .into_iter()
-        .sorted_by_key(|x| (*x).0)
+        .sorted_by_key(|x| x.0)
         .map(|x| x.1)
         .collect_vec();
     (dropped.len(), rests_on, dropped_blocks)
@@ -1,56 +1,59 @@
-use crate::util::{AdventHelper, BitSetGrid};
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, Grid, ParseError, Point};
 use array2d::Array2D;
-use bit_set::BitSet;
 use itertools::Itertools;
 use rayon::prelude::*;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 const PARALLEL_DEPTH: u32 = 6;
-pub fn main() {
-    let advent = AdventHelper::from_file_name(file!());
-    let grid = advent.parse_grid_2d();
 
-    advent.part1("Longest walk: {}", longest_walk(&grid, false));
-    advent.part2("Longest walk: {}", longest_walk(&grid, true));
-}
+type Connections = FxHashMap<Point, Vec<(Point, usize)>>;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Grid<char>;
+
+    const PART1: &'static str = "Longest walk: {}";
+    const PART2: &'static str = "Longest walk: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.grid()
+    }
 
-fn direct_successors(
-    node: &(usize, usize),
-    grid: &Array2D<char>,
-    ignore_slopes: bool,
-) -> Vec<(usize, usize)> {
-    let (y, x) = node;
-    let right = (*y, x + 1);
-    let left = (*y, x - 1);
-    let up = (y - 1, *x);
-    let down = (y + 1, *x);
+    fn part1(&self, grid: &Self::Input) -> Answer {
+        longest_walk(grid, false).into()
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Answer {
+        longest_walk(grid, true).into()
+    }
+}
 
-    let is_open = |(y, x)| grid.get(y, x).map(|c| *c != '#').unwrap_or(false);
+fn direct_successors(node: &Point, grid: &Grid<char>, ignore_slopes: bool) -> Vec<Point> {
+    let is_open = |p: &Point| grid.get(p).map(|c| *c != '#').unwrap_or(false);
 
-    let c = grid[(*y, *x)];
+    let c = grid[*node];
     let mut succ = vec![];
     if c == '.' || c == '>' || ignore_slopes {
-        succ.push(right)
+        succ.push(node.right())
     }
     if c == '.' || c == '<' || ignore_slopes {
-        succ.push(left)
+        succ.push(node.left())
     }
     if c == '.' || c == '^' || ignore_slopes {
-        succ.push(up)
+        succ.push(node.up())
     }
     if c == '.' || c == 'v' || ignore_slopes {
-        succ.push(down)
+        succ.push(node.down())
     }
 
-    succ.iter().filter(|s| is_open(**s)).cloned().collect_vec()
+    succ.into_iter().filter(is_open).collect_vec()
 }
 
-fn simplify(
-    grid: &Array2D<char>,
-    ignore_slopes: bool,
-) -> FxHashMap<(usize, usize), Vec<((usize, usize), usize)>> {
-    let interesting_nodes: FxHashSet<(usize, usize)> = grid
-        .enumerate_row_major()
+fn simplify(grid: &Grid<char>, ignore_slopes: bool) -> Connections {
+    let interesting_nodes: FxHashSet<Point> = grid
+        .iter()
         .filter(|(p, c)| {
             ((ignore_slopes && **c != '#') || (!ignore_slopes && **c == '.'))
                 && direct_successors(p, grid, ignore_slopes).len() != 2
@@ -60,7 +63,7 @@ fn simplify(
     let mut connections = FxHashMap::default();
     for node in &interesting_nodes {
         let mut conns = vec![];
-        'middle: for mut n in direct_successors(&node, grid, ignore_slopes) {
+        'middle: for mut n in direct_successors(node, grid, ignore_slopes) {
             let mut visited = FxHashSet::default();
             visited.insert(*node);
             while !interesting_nodes.contains(&n) {
@@ -82,14 +85,12 @@ fn simplify(
     connections
 }
 
-fn longest_walk(grid: &Array2D<char>, ignore_slopes: bool) -> usize {
-    let start: (usize, usize) = (0, 1);
-    let end = (grid.column_len() - 1, grid.row_len() - 2);
-    let map: FxHashMap<(usize, usize), Vec<((usize, usize), usize)>> =
-        simplify(grid, ignore_slopes);
+fn longest_walk(grid: &Grid<char>, ignore_slopes: bool) -> usize {
+    let start = Point::new(1, 0);
+    let end = Point::new(grid.width() - 2, grid.height() - 1);
+    let map: Connections = simplify(grid, ignore_slopes);
 
-    let to_nodes: FxHashMap<(usize, usize), usize> =
-        map.keys().enumerate().map(|(i, n)| (*n, i)).collect();
+    let to_nodes: FxHashMap<Point, usize> = map.keys().enumerate().map(|(i, n)| (*n, i)).collect();
 
     let start = to_nodes[&start];
     let end = to_nodes[&end];
@@ -112,7 +113,7 @@ fn longest_path(
     start: usize,
     end: usize,
     visited: u64,
-    neighbours: &Vec<Vec<usize>>,
+    neighbours: &[Vec<usize>],
     costs: &Array2D<usize>,
 ) -> Option<usize> {
     if start == end {
@@ -127,11 +128,8 @@ fn longest_path(
                     return None;
                 }
                 let next_visited = visited | (1 << next);
-                if let Some(length) = longest_path(*next, end, next_visited, neighbours, costs) {
-                    Some(costs[(start, *next)] + length)
-                } else {
-                    None
-                }
+                longest_path(*next, end, next_visited, neighbours, costs)
+                    .map(|length| costs[(start, *next)] + length)
             })
             .max();
     }
@@ -144,7 +142,7 @@ fn longest_path(
         let next_visited = visited | (1 << next);
         if let Some(length) = longest_path(*next, end, next_visited, neighbours, costs) {
             let new_length = costs[(start, *next)] + length;
-            if longest == None || longest.unwrap() < new_length {
+            if longest.is_none() || longest.unwrap() < new_length {
                 longest = Some(new_length)
             }
         };
@@ -1,22 +1,32 @@
-use crate::util::AdventHelper;
+use crate::scan;
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, Line, ParseError, Vec3};
 use itertools::Itertools;
 use num::signum;
-use std::fmt::Display;
-use std::ops::Add;
 use std::str::FromStr;
 
-pub fn main() {
-    let advent = AdventHelper::from_file_name(file!());
-    let hailstones = advent.parse_from_strings();
+pub struct Day24;
 
-    advent.part1(
-        "Intersections: {}",
-        intersecting_paths(&hailstones, 200000000000000.0, 400000000000000.0),
-    );
-    advent.part2("Stone start sum: {}", projected_intersections(&hailstones));
+impl Solution for Day24 {
+    type Input = Vec<Hailstone>;
+
+    const PART1: &'static str = "Intersections: {}";
+    const PART2: &'static str = "Stone start sum: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.parse_from_strings()
+    }
+
+    fn part1(&self, hailstones: &Self::Input) -> Answer {
+        intersecting_paths(hailstones, 200000000000000.0, 400000000000000.0).into()
+    }
+
+    fn part2(&self, hailstones: &Self::Input) -> Answer {
+        projected_intersections(hailstones).into()
+    }
 }
 
-fn intersecting_paths(hailstones: &Vec<Hailstone>, lower_bound: f64, upper_bound: f64) -> usize {
+fn intersecting_paths(hailstones: &[Hailstone], lower_bound: f64, upper_bound: f64) -> usize {
     let mut count = 0;
     for (a, b) in hailstones.iter().tuple_combinations() {
         let Vec3 { x: x1, y: y1, z: _ } = a.position;
@@ -47,63 +57,45 @@ fn intersecting_paths(hailstones: &Vec<Hailstone>, lower_bound: f64, upper_bound
     count
 }
 #[derive(Debug, Clone)]
-struct Hailstone {
-    position: Vec3,
-    velocity: Vec3,
-}
-
-#[derive(Copy, Clone, Debug)]
-struct Vec3 {
-    x: i128,
-    y: i128,
-    z: i128,
-}
-
-impl Add for Vec3 {
-    type Output = Vec3;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Vec3 {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-            z: self.z + rhs.z,
-        }
-    }
+pub struct Hailstone {
+    position: Vec3<i128>,
+    velocity: Vec3<i128>,
 }
 
 impl FromStr for Hailstone {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (position, velocity) = s
-            .split(" @ ")
-            .map(|v| {
-                let (x, y, z) = v
-                    .split(", ")
-                    .map(|x| x.parse().unwrap())
-                    .collect_tuple()
-                    .unwrap();
-                Vec3 { x, y, z }
-            })
-            .collect_tuple()
-            .unwrap();
-        Ok(Hailstone { position, velocity })
+        let (x, y, z, dx, dy, dz) = scan!(
+            Line(s),
+            "{x}, {y}, {z} @ {dx}, {dy}, {dz}",
+            x: i128,
+            y: i128,
+            z: i128,
+            dx: i128,
+            dy: i128,
+            dz: i128
+        )?;
+        Ok(Hailstone {
+            position: Vec3::new(x, y, z),
+            velocity: Vec3::new(dx, dy, dz),
+        })
     }
 }
 
-fn projected_intersections(hailstones: &Vec<Hailstone>) -> i128 {
+fn projected_intersections(hailstones: &[Hailstone]) -> i128 {
     let mut search_area = 1;
     loop {
         for x in -search_area..=search_area {
             for y in -search_area..=search_area {
-                let xy = intersect_project(hailstones, Vec3 { x, y, z: 0 }, |v| (v.x, v.y));
+                let xy = intersect_project(hailstones, Vec3::new(x, y, 0), |v| (v.x, v.y));
                 if xy.is_none() {
                     continue;
                 }
                 for z in -search_area..=search_area {
-                    let xz = intersect_project(hailstones, Vec3 { x, y, z }, |v| (v.x, v.z));
+                    let xz = intersect_project(hailstones, Vec3::new(x, y, z), |v| (v.x, v.z));
                     if let Some((found_x, found_z)) = xz {
-                        let yz = intersect_project(hailstones, Vec3 { x, y, z }, |v| (v.y, v.z));
+                        let yz = intersect_project(hailstones, Vec3::new(x, y, z), |v| (v.y, v.z));
                         if let Some((found_y, _)) = yz {
                             return found_x + found_y + found_z;
                         }
@@ -113,14 +105,12 @@ fn projected_intersections(hailstones: &Vec<Hailstone>) -> i128 {
         }
         search_area *= 2;
     }
-
-    panic!("not found")
 }
 
 fn intersect_project(
-    hailstones: &Vec<Hailstone>,
-    modifier: Vec3,
-    f: fn(Vec3) -> (i128, i128),
+    hailstones: &[Hailstone],
+    modifier: Vec3<i128>,
+    f: fn(Vec3<i128>) -> (i128, i128),
 ) -> Option<(i128, i128)> {
     let mut intersect = None;
     for (a, b) in hailstones.iter().tuple_combinations() {
@@ -1,16 +1,23 @@
-use crate::util::AdventHelper;
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, ParseError};
 use itertools::Itertools;
 use pathfinding::prelude::{edmonds_karp_dense, strongly_connected_component};
 use rustc_hash::{FxHashMap, FxHashSet};
 
-pub fn main() {
-    let advent = AdventHelper::from_file_name(file!());
-    let adjacencies = parse(advent.parse_from_strings());
+pub struct Day25;
 
-    advent.part1(
-        "Product of cluster size: {}",
-        product_of_components(&adjacencies),
-    );
+impl Solution for Day25 {
+    type Input = FxHashMap<usize, Vec<usize>>;
+
+    const PART1: &'static str = "Product of cluster size: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        Ok(parse(advent.parse_from_strings()?))
+    }
+
+    fn part1(&self, adjacencies: &Self::Input) -> Answer {
+        product_of_components(adjacencies).into()
+    }
 }
 
 fn product_of_components(adjacencies: &FxHashMap<usize, Vec<usize>>) -> usize {
@@ -62,7 +69,7 @@ fn parse(input: Vec<String>) -> FxHashMap<usize, Vec<usize>> {
             }
         }
     }
-    let mut to_idx: FxHashMap<String, usize> = succ
+    let to_idx: FxHashMap<String, usize> = succ
         .keys()
         .enumerate()
         .map(|(a, b)| (b.clone(), a))
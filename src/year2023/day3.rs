@@ -0,0 +1,105 @@
+use crate::solution::{Answer, Solution};
+use crate::util::Dir::Right;
+use crate::util::{AdventHelper, ParseError, Point};
+use itertools::Itertools;
+use regex::Regex;
+use std::collections::HashMap;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = (Vec<u32>, Vec<Part>);
+
+    const PART1: &'static str = "Sum of part numbers: {}";
+    const PART2: &'static str = "Sum of gear ratios: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        let lines: Vec<String> = advent.parse_from_strings()?;
+        let symbols = find_symbols(&lines);
+        Ok(find_labels(&lines, &symbols))
+    }
+
+    fn part1(&self, (part_numbers, _): &Self::Input) -> Answer {
+        sum_part_numbers(part_numbers).into()
+    }
+
+    fn part2(&self, (_, parts): &Self::Input) -> Answer {
+        sum_gear_ratios(parts).into()
+    }
+}
+
+fn sum_part_numbers(parts: &[u32]) -> u32 {
+    parts.iter().sum()
+}
+
+fn sum_gear_ratios(parts: &[Part]) -> u32 {
+    let _count = 0;
+    parts
+        .iter()
+        .filter(|p| p.symbol == '*')
+        .into_group_map_by(|g| g.position)
+        .values()
+        .filter(|parts| parts.len() == 2)
+        .map(|parts| parts[0].number * parts[1].number)
+        .sum()
+}
+
+fn find_symbols(lines: &[String]) -> HashMap<Point, char> {
+    let mut symbols = HashMap::new();
+    for (y, line) in lines.iter().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if c != '.' && !c.is_ascii_digit() {
+                symbols.insert(Point::new(x as i32, y as i32), c);
+            }
+        }
+    }
+    symbols
+}
+
+#[derive(Debug, Clone)]
+pub struct Part {
+    number: u32,
+    position: Point,
+    symbol: char,
+}
+
+fn find_labels(lines: &[String], symbols: &HashMap<Point, char>) -> (Vec<u32>, Vec<Part>) {
+    let mut part_numbers: Vec<u32> = vec![];
+    let mut parts: Vec<Part> = vec![];
+    let re = Regex::new(r"\d+").unwrap();
+    for (y, line) in lines.iter().enumerate() {
+        for m in re.find_iter(line) {
+            let number = m.as_str().parse().unwrap();
+            let new_parts = make_part(number, m.start() as i32, y as i32, m.len(), symbols);
+            if !new_parts.is_empty() {
+                part_numbers.push(number)
+            }
+            for new_part in new_parts {
+                parts.push(new_part);
+            }
+        }
+    }
+    (part_numbers, parts)
+}
+
+fn make_part(
+    number: u32,
+    label_x: i32,
+    label_y: i32,
+    length: usize,
+    symbols: &HashMap<Point, char>,
+) -> Vec<Part> {
+    let start = Point::new(label_x, label_y);
+    (0..length)
+        .map(|i| start.mv_mulitple(Right, i as i32))
+        .flat_map(|digit| digit.neighbours8())
+        .unique()
+        .filter_map(|position| {
+            symbols.get(&position).map(|&symbol| Part {
+                number,
+                position,
+                symbol,
+            })
+        })
+        .collect()
+}
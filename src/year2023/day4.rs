@@ -0,0 +1,104 @@
+use crate::scan;
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, Line, ParseError};
+use itertools::Itertools;
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+
+    const PART1: &'static str = "Sum of scores: {}";
+    const PART2: &'static str = "Total cards: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.parse_from_strings()
+    }
+
+    fn part1(&self, cards: &Self::Input) -> Answer {
+        sum_scores(cards).into()
+    }
+
+    fn part2(&self, cards: &Self::Input) -> Answer {
+        count_scratchcards(cards).into()
+    }
+}
+
+fn count_scratchcards(scratchcards: &[Card]) -> usize {
+    let mut number_of_cards: HashMap<u32, usize> =
+        scratchcards.iter().map(|card| (card.id, 1)).collect();
+    for card in scratchcards {
+        let multiplicity = number_of_cards[&card.id];
+        let count = card.count_winning();
+        for id in card.id + 1..=card.id + count {
+            number_of_cards.insert(id, number_of_cards[&id] + multiplicity);
+        }
+    }
+    number_of_cards.values().sum()
+}
+
+fn sum_scores(cards: &[Card]) -> u32 {
+    cards.iter().map(|card| card.score()).sum()
+}
+
+pub struct Card {
+    id: u32,
+    winning_numbers: Vec<u32>,
+    actual_numbers: Vec<u32>,
+}
+
+impl Card {
+    pub(crate) fn score(&self) -> u32 {
+        let mut score = 0;
+        for n in &self.actual_numbers {
+            if self.winning_numbers.contains(n) {
+                if score == 0 {
+                    score = 1
+                } else {
+                    score *= 2
+                }
+            }
+        }
+        score
+    }
+
+    pub(crate) fn count_winning(&self) -> u32 {
+        let mut score = 0;
+        for n in &self.actual_numbers {
+            if self.winning_numbers.contains(n) {
+                score += 1
+            }
+        }
+        score
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = Line(s);
+        let (id, raw_win, raw_act) = scan!(
+            line,
+            "Card {id}: {winning} | {actual}",
+            id: u32,
+            winning: &str,
+            actual: &str
+        )?;
+        let numbers = |raw: &str| {
+            raw.split_whitespace()
+                .map(|number| line.parse(number))
+                .try_collect()
+        };
+        let winning_numbers = numbers(raw_win)?;
+        let actual_numbers = numbers(raw_act)?;
+        Ok(Card {
+            id,
+            winning_numbers,
+            actual_numbers,
+        })
+    }
+}
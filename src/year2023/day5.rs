@@ -1,22 +1,30 @@
-use crate::util::AdventHelper;
+use crate::scan;
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, ParseError};
 use itertools::Itertools;
 use std::cmp::{max, min};
 
 use std::ops::Range;
 
-pub fn main() {
-    let advent = AdventHelper::from_file_name(file!());
-    let lines: Vec<String> = advent.parse_from_strings();
-    let almanac = parse_seed_maps(&lines);
-
-    advent.part1(
-        "Lowest location number: {}",
-        lowest_location_number(&almanac),
-    );
-    advent.part2(
-        "Lowest location number: {}",
-        lowest_location_range_number(&almanac),
-    );
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+
+    const PART1: &'static str = "Lowest location number: {}";
+    const PART2: &'static str = "Lowest location number: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        parse_almanac(advent)
+    }
+
+    fn part1(&self, almanac: &Self::Input) -> Answer {
+        lowest_location_number(almanac).into()
+    }
+
+    fn part2(&self, almanac: &Self::Input) -> Answer {
+        lowest_location_range_number(almanac).into()
+    }
 }
 
 fn lowest_location_number(almanac: &Almanac) -> u64 {
@@ -27,7 +35,7 @@ fn lowest_location_number(almanac: &Almanac) -> u64 {
             almanac
                 .maps
                 .iter()
-                .fold(n.clone(), |number, map| map.apply(number))
+                .fold(*n, |number, map| map.apply(number))
         })
         .min()
         .unwrap()
@@ -114,7 +122,7 @@ impl AlmanacMap {
 }
 
 #[derive(Debug)]
-struct Almanac {
+pub struct Almanac {
     seeds: Vec<u64>,
     maps: Vec<AlmanacMap>,
 }
@@ -130,27 +138,34 @@ impl Almanac {
     }
 }
 
-fn parse_seed_maps(lines: &Vec<String>) -> Almanac {
+fn parse_almanac(advent: &AdventHelper) -> Result<Almanac, ParseError> {
     let mut seeds = vec![];
-    let mut maps = vec![];
-    for line in lines {
-        if let Some(line) = line.strip_prefix("seeds: ") {
-            seeds = line.split(" ").map(|n| n.parse().unwrap()).collect_vec()
-        } else if line.ends_with(" map:") {
-            maps.push(AlmanacMap { mappings: vec![] })
-        } else if !line.is_empty() {
-            let (destination_range_start, source_range_start, range_length) = line
-                .split(" ")
-                .map(|s| s.parse::<u64>().unwrap())
-                .collect_tuple()
-                .unwrap();
-            maps.last_mut().unwrap().mappings.push(AlmanacMapping {
+    let maps = advent.parse_headed_sections(|section| {
+        if section.header.as_deref() == Some("seeds") {
+            seeds = section
+                .parse_lines_with(|line| line.parse_list(line.0, " "))?
+                .concat();
+            return Ok(None);
+        }
+        let mappings = section.parse_lines_with(|line| {
+            let (destination_range_start, source_range_start, range_length) = scan!(
+                line,
+                "{destination} {source} {length}",
+                destination: u64,
+                source: u64,
+                length: u64
+            )?;
+            Ok(AlmanacMapping {
                 source_range_start,
                 destination_range_start,
                 range_length,
             })
-        }
-    }
-
-    Almanac { seeds, maps }
+        })?;
+        Ok(Some(AlmanacMap { mappings }))
+    })?;
+
+    Ok(Almanac {
+        seeds,
+        maps: maps.into_iter().flatten().collect(),
+    })
 }
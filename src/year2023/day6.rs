@@ -0,0 +1,66 @@
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, ParseError};
+use rayon::prelude::*;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = (Vec<Race>, Race);
+
+    const PART1: &'static str = "product: {}";
+    const PART2: &'static str = "ways to win: {}";
+
+    fn parse(&self, _advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        let races = vec![
+            Race {
+                time: 46,
+                distance: 214,
+            },
+            Race {
+                time: 80,
+                distance: 1177,
+            },
+            Race {
+                time: 78,
+                distance: 1402,
+            },
+            Race {
+                time: 66,
+                distance: 1024,
+            },
+        ];
+        let race = Race {
+            time: 46807866,
+            distance: 214117714021024,
+        };
+        Ok((races, race))
+    }
+
+    fn part1(&self, (races, _): &Self::Input) -> Answer {
+        multiply_ways_to_win(races).into()
+    }
+
+    fn part2(&self, (_, race): &Self::Input) -> Answer {
+        ways_to_win(race).into()
+    }
+}
+
+fn multiply_ways_to_win(races: &[Race]) -> usize {
+    races.iter().map(ways_to_win).product()
+}
+
+fn ways_to_win(race: &Race) -> usize {
+    (0..=race.time)
+        .into_par_iter()
+        .filter(|seconds_pressed| {
+            let seconds_remaining = race.time - seconds_pressed;
+            let distance = seconds_remaining * seconds_pressed;
+            distance > race.distance
+        })
+        .count()
+}
+
+pub struct Race {
+    time: i64,
+    distance: i64,
+}
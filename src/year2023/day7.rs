@@ -1,17 +1,32 @@
-use crate::util::AdventHelper;
+use crate::scan;
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, Line, ParseError};
 use itertools::Itertools;
 
 use std::str::FromStr;
 
-pub fn main() {
-    let advent = AdventHelper::from_file_name(file!());
-    let plays: Vec<Play> = advent.parse_from_strings();
+pub struct Day7;
 
-    advent.part1("winnings: {}", winnings(&plays, false));
-    advent.part2("winnings: {}", winnings(&plays, true));
+impl Solution for Day7 {
+    type Input = Vec<Play>;
+
+    const PART1: &'static str = "winnings: {}";
+    const PART2: &'static str = "winnings: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.parse_from_strings()
+    }
+
+    fn part1(&self, plays: &Self::Input) -> Answer {
+        winnings(plays, false).into()
+    }
+
+    fn part2(&self, plays: &Self::Input) -> Answer {
+        winnings(plays, true).into()
+    }
 }
 
-fn winnings(plays: &Vec<Play>, joker: bool) -> usize {
+fn winnings(plays: &[Play], joker: bool) -> usize {
     plays
         .iter()
         .sorted_by_key(|p| p.hand.score(joker))
@@ -20,6 +35,9 @@ fn winnings(plays: &Vec<Play>, joker: bool) -> usize {
         .sum()
 }
 
+const CARDS: &str = "AKQJT98765432";
+
+/// Five cards, each one of [`CARDS`]
 struct Hand(String);
 
 impl Hand {
@@ -80,19 +98,42 @@ impl Hand {
     }
 }
 
-struct Play {
+pub struct Play {
     hand: Hand,
     bid: usize,
 }
 
 impl FromStr for Play {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (a, b) = s.split(" ").collect_tuple().unwrap();
+        let line = Line(s);
+        let (hand, bid) = scan!(line, "{hand} {bid}", hand: &str, bid: usize)?;
+        if hand.chars().count() != 5 || !hand.chars().all(|c| CARDS.contains(c)) {
+            return Err(line.error(hand, "expected 5 cards of AKQJT98765432, found"));
+        }
         Ok(Play {
-            hand: Hand(a.to_string()),
-            bid: b.parse().unwrap(),
+            hand: Hand(hand.to_string()),
+            bid,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_malformed_hands() {
+        let error = |s: &str| s.parse::<Play>().err().unwrap();
+
+        assert!("32T3K 765".parse::<Play>().is_ok());
+        assert_eq!(
+            error("32T3 765").message,
+            "expected 5 cards of AKQJT98765432, found"
+        );
+        assert_eq!(error("32T3KK 765").column, 1);
+        let error = error("32t3K 765");
+        assert_eq!((error.column, error.text.as_str()), (1, "32t3K"));
+    }
+}
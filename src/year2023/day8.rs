@@ -0,0 +1,88 @@
+use crate::scan;
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, Line, ParseError};
+use itertools::Itertools;
+
+use std::collections::HashMap;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Network;
+
+    const PART1: &'static str = "steps: {}";
+    const PART2: &'static str = "steps: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        let lines: Vec<String> = advent.parse_from_strings()?;
+        parse(&lines)
+    }
+
+    fn part1(&self, network: &Self::Input) -> Answer {
+        count_steps(network, "AAA".to_string(), |s| s == "ZZZ").into()
+    }
+
+    fn part2(&self, network: &Self::Input) -> Answer {
+        count_ghost_steps(network).into()
+    }
+}
+fn count_steps(network: &Network, begin: String, end: fn(&String) -> bool) -> i32 {
+    let instructions = network.instructions.chars().cycle();
+    let mut steps = 0;
+    let mut current = begin;
+    for instruction in instructions {
+        current = network
+            .connections
+            .get(&(current.clone(), instruction))
+            .unwrap()
+            .clone();
+        steps += 1;
+        if end(&current) {
+            break;
+        }
+    }
+    steps
+}
+
+fn count_ghost_steps(network: &Network) -> u64 {
+    let current: Vec<String> = network
+        .connections
+        .keys()
+        .map(|(s, _)| s.clone())
+        .filter(|x| x.ends_with("A"))
+        .unique()
+        .collect_vec();
+    let mut lcm = 1;
+    for x in current {
+        let y = count_steps(network, x.clone(), |x| x.ends_with("Z")) as u64;
+        lcm = num::integer::lcm(lcm, y);
+    }
+    lcm
+}
+
+fn parse(lines: &[String]) -> Result<Network, ParseError> {
+    let instructions = lines[0].clone();
+    let mut connections: HashMap<(String, char), String> = HashMap::new();
+    for (i, line) in lines.iter().enumerate().skip(2) {
+        let (from, left, right) = scan!(
+            Line(line),
+            "{from} = ({left}, {right})",
+            from: String,
+            left: String,
+            right: String
+        )
+        .map_err(|e| e.at_line(i + 1))?;
+        connections.insert((from.clone(), 'L'), left);
+        connections.insert((from, 'R'), right);
+    }
+
+    Ok(Network {
+        instructions,
+        connections,
+    })
+}
+
+pub struct Network {
+    instructions: String,
+    connections: HashMap<(String, char), String>,
+}
@@ -0,0 +1,61 @@
+use crate::solution::{Answer, Solution};
+use crate::util::{AdventHelper, ParseError};
+use itertools::Itertools;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i32>>;
+
+    const PART1: &'static str = "sum of extrapolated values: {}";
+    const PART2: &'static str = "sum of extrapolated values: {}";
+
+    fn parse(&self, advent: &AdventHelper) -> Result<Self::Input, ParseError> {
+        advent.parse_sequences_from_strings(" ")
+    }
+
+    fn part1(&self, sequences: &Self::Input) -> Answer {
+        sum_extrapolated_values(sequences).into()
+    }
+
+    fn part2(&self, sequences: &Self::Input) -> Answer {
+        sum_extrapolated_first_values(sequences).into()
+    }
+}
+
+fn sum_extrapolated_values(sequences: &[Vec<i32>]) -> i32 {
+    sequences.iter().map(|s| extrapolated_value(s)).sum()
+}
+
+fn sum_extrapolated_first_values(sequences: &[Vec<i32>]) -> i32 {
+    sequences.iter().map(|s| extrapolated_first_value(s)).sum()
+}
+
+fn extrapolated_value(sequence: &[i32]) -> i32 {
+    let derivatives = derivatives(sequence);
+    derivatives.iter().map(|x| x.last().unwrap()).sum()
+}
+
+fn extrapolated_first_value(sequence: &[i32]) -> i32 {
+    let derivatives = derivatives(sequence);
+    let mut value = 0;
+    for x in derivatives.iter().rev() {
+        value = x.first().unwrap() - value
+    }
+    value
+}
+
+fn derivatives(sequence: &[i32]) -> Vec<Vec<i32>> {
+    let mut derivatives = vec![sequence.to_vec()];
+    while derivatives.last().unwrap().iter().any(|x| *x != 0) {
+        let next_derivative = derivatives
+            .last()
+            .unwrap()
+            .iter()
+            .tuple_windows()
+            .map(|(a, b)| b - a)
+            .collect_vec();
+        derivatives.push(next_derivative);
+    }
+    derivatives
+}